@@ -17,8 +17,8 @@ pub fn generate_data(len: usize, distribution: &str) -> Vec<i32> {
             }
         }
         "few_unique" => {
-            for i in 0..len {
-                data[i] = (i % 10) as i32;
+            for (i, x) in data.iter_mut().enumerate() {
+                *x = (i % 10) as i32;
             }
         }
         _ => {}
@@ -1,9 +1,21 @@
+pub mod compression;
 pub mod error;
+pub mod numeric;
+pub mod range_query;
 pub mod search;
+pub mod signal;
 pub mod sort;
+pub mod stats;
 pub mod string;
+pub mod util;
 
 // Re-export all modules
+pub use compression::*;
+pub use numeric::*;
+pub use range_query::*;
 pub use search::*;
+pub use signal::*;
 pub use sort::*;
+pub use stats::*;
 pub use string::*;
+pub use util::*;
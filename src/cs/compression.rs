@@ -0,0 +1,5 @@
+pub mod lz77;
+pub mod rle;
+
+// Re-export types
+pub use lz77::Lz77Token;
@@ -0,0 +1,269 @@
+use crate::cs::error::{Error, Result};
+
+/// Default number of preceding bytes searched for matches.
+pub const DEFAULT_WINDOW_SIZE: usize = 4096;
+
+/// One LZ77 token: copy `length` bytes starting `offset` bytes back, then emit `next`.
+///
+/// A token with `length == 0` is a literal and has `offset == 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lz77Token {
+    /// Distance back from the current position to the start of the copy.
+    pub offset: usize,
+    /// Number of bytes to copy; the copy may overlap the bytes it produces.
+    pub length: usize,
+    /// The byte following the copied bytes.
+    pub next: u8,
+}
+
+/// Compresses bytes into LZ77 `(offset, length, next_byte)` tokens.
+///
+/// At each position the window of up to `window_size` preceding bytes is searched for
+/// the longest match, which may run past the current position. Matches stop one byte
+/// short of the end of the input, so every token carries a following byte.
+///
+/// # Arguments
+/// * `data` - The bytes to compress
+/// * `window_size` - How far back matches may start; zero produces only literals
+///
+/// # Returns
+/// * The tokens, which [`decompress`] turns back into `data`
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::compression::lz77;
+/// #
+/// let data = b"abcabcabcabcx";
+/// let tokens = lz77::compress(data, lz77::DEFAULT_WINDOW_SIZE);
+/// assert_eq!(tokens.len(), 4);
+/// assert_eq!(lz77::decompress(&tokens, data.len()).unwrap(), data);
+/// ```
+///
+/// # Performance
+/// * Time: O(n × w × L) in the worst case, where w is the window size and L the
+///   longest match, since the window is searched by brute force
+/// * Space: O(n) for the tokens
+pub fn compress(data: &[u8], window_size: usize) -> Vec<Lz77Token> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < data.len() {
+        // Leave at least one byte for `next`
+        let max_length = data.len() - pos - 1;
+        let window_start = pos.saturating_sub(window_size);
+
+        let mut best = (0, 0);
+        for start in window_start..pos {
+            let length = (0..max_length)
+                .take_while(|&i| data[start + i] == data[pos + i])
+                .count();
+            // Prefer the closest match among equally long ones
+            if length > 0 && length >= best.1 {
+                best = (pos - start, length);
+            }
+        }
+
+        let (offset, length) = best;
+        tokens.push(Lz77Token {
+            offset,
+            length,
+            next: data[pos + length],
+        });
+        pos += length + 1;
+    }
+    tokens
+}
+
+/// Reconstructs the bytes encoded by [`compress`].
+///
+/// Each token can expand to any number of bytes, so a handful of crafted tokens could
+/// demand an unbounded amount of memory. The output is checked against
+/// `max_output_len` before each token is expanded.
+///
+/// # Arguments
+/// * `tokens` - The tokens to expand
+/// * `max_output_len` - The largest output to accept, e.g. the original length if
+///   it was stored alongside the tokens
+///
+/// # Returns
+/// * `Result<Vec<u8>>` - The decompressed bytes
+///
+/// # Errors
+/// * `Error::InvalidInput` if a token refers back before the start of the output, or
+///   has a nonzero length with a zero offset
+/// * `Error::InputTooLarge` if the output would exceed `max_output_len` bytes
+///
+/// # Performance
+/// * Time: O(n) in the size of the output
+/// * Space: O(n) in the size of the output
+pub fn decompress(tokens: &[Lz77Token], max_output_len: usize) -> Result<Vec<u8>> {
+    let mut output = Vec::new();
+
+    for token in tokens {
+        let expanded_len = output.len().saturating_add(token.length).saturating_add(1);
+        if expanded_len > max_output_len {
+            return Err(Error::input_too_large(expanded_len, max_output_len));
+        }
+
+        if token.length > 0 {
+            if token.offset == 0 || token.offset > output.len() {
+                return Err(Error::invalid_input(format!(
+                    "LZ77 token offset {} is invalid at output position {}",
+                    token.offset,
+                    output.len()
+                )));
+            }
+            // Copy byte by byte, since the source may overlap the bytes being written
+            let start = output.len() - token.offset;
+            for i in 0..token.length {
+                output.push(output[start + i]);
+            }
+        }
+        output.push(token.next);
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(data: &[u8], window_size: usize) -> Vec<Lz77Token> {
+        let tokens = compress(data, window_size);
+        assert_eq!(decompress(&tokens, data.len()).unwrap(), data);
+        tokens
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(compress(&[], DEFAULT_WINDOW_SIZE).is_empty());
+        assert!(decompress(&[], 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_literals_only() {
+        let tokens = round_trip(b"abcd", DEFAULT_WINDOW_SIZE);
+        assert!(tokens.iter().all(|t| t.length == 0 && t.offset == 0));
+        assert_eq!(tokens.len(), 4);
+    }
+
+    #[test]
+    fn test_overlapping_match() {
+        // After the literal 'a', one token copies the run of 'a's from itself
+        let tokens = round_trip(b"aaaaaaaaab", DEFAULT_WINDOW_SIZE);
+        assert_eq!(
+            tokens,
+            vec![
+                Lz77Token {
+                    offset: 0,
+                    length: 0,
+                    next: b'a'
+                },
+                Lz77Token {
+                    offset: 1,
+                    length: 8,
+                    next: b'b'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repetitive_data_compresses() {
+        let data: Vec<u8> = b"the rain in spain ".repeat(50);
+        let tokens = round_trip(&data, DEFAULT_WINDOW_SIZE);
+        assert!(tokens.len() < 20, "{} tokens", tokens.len());
+    }
+
+    #[test]
+    fn test_round_trip_arbitrary_bytes() {
+        // Pseudo-random bytes from a linear congruential generator
+        let mut state = 12_345u32;
+        let data: Vec<u8> = (0..3_000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (state >> 16) as u8
+            })
+            .collect();
+        round_trip(&data, DEFAULT_WINDOW_SIZE);
+        round_trip(&data, 16);
+        round_trip(&data, 0);
+    }
+
+    #[test]
+    fn test_window_limits_offsets() {
+        let data: Vec<u8> = b"0123456789".repeat(20);
+        for token in round_trip(&data, 12) {
+            assert!(token.offset <= 12);
+        }
+        let tokens = round_trip(&data, 5);
+        assert!(tokens.iter().all(|t| t.length == 0));
+    }
+
+    #[test]
+    fn test_invalid_tokens() {
+        let bad_offset = [Lz77Token {
+            offset: 3,
+            length: 2,
+            next: b'x',
+        }];
+        assert!(matches!(
+            decompress(&bad_offset, 100),
+            Err(Error::InvalidInput(_))
+        ));
+
+        let zero_offset = [
+            Lz77Token {
+                offset: 0,
+                length: 0,
+                next: b'a',
+            },
+            Lz77Token {
+                offset: 0,
+                length: 1,
+                next: b'b',
+            },
+        ];
+        assert!(matches!(
+            decompress(&zero_offset, 100),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_output_limit() {
+        let data = b"abcabcabcabcx";
+        let tokens = compress(data, DEFAULT_WINDOW_SIZE);
+        assert!(matches!(
+            decompress(&tokens, data.len() - 1),
+            Err(Error::InputTooLarge {
+                length: 13,
+                max_length: 12
+            })
+        ));
+    }
+
+    #[test]
+    fn test_malicious_length_is_rejected() {
+        // A single seed byte followed by a copy of usize::MAX bytes
+        let tokens = [
+            Lz77Token {
+                offset: 0,
+                length: 0,
+                next: b'a',
+            },
+            Lz77Token {
+                offset: 1,
+                length: usize::MAX,
+                next: b'b',
+            },
+        ];
+        assert!(matches!(
+            decompress(&tokens, 1 << 20),
+            Err(Error::InputTooLarge {
+                length: usize::MAX,
+                max_length: 1_048_576
+            })
+        ));
+    }
+}
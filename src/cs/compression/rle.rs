@@ -0,0 +1,114 @@
+use crate::cs::error::{Error, Result};
+
+/// Encodes bytes with run-length encoding.
+///
+/// Each run of equal bytes becomes a `(count, byte)` pair, with runs longer than
+/// 255 bytes split across several pairs.
+///
+/// # Arguments
+/// * `data` - The bytes to encode
+///
+/// # Returns
+/// * The encoded pairs, flattened as `[count, byte, count, byte, ...]`
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::compression::rle;
+/// #
+/// assert_eq!(rle::encode(b"aaabcc"), vec![3, b'a', 1, b'b', 2, b'c']);
+/// assert_eq!(rle::decode(&rle::encode(b"aaabcc")).unwrap(), b"aaabcc");
+/// ```
+///
+/// # Performance
+/// * Time: O(n)
+/// * Space: O(n) in the worst case, when no byte repeats
+pub fn encode(data: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    let mut bytes = data.iter().copied().peekable();
+
+    while let Some(byte) = bytes.next() {
+        let mut count: u8 = 1;
+        while count < u8::MAX && bytes.next_if_eq(&byte).is_some() {
+            count += 1;
+        }
+        encoded.push(count);
+        encoded.push(byte);
+    }
+    encoded
+}
+
+/// Decodes bytes produced by [`encode`].
+///
+/// # Arguments
+/// * `encoded` - The `(count, byte)` pairs to expand
+///
+/// # Returns
+/// * `Result<Vec<u8>>` - The original bytes
+///
+/// # Errors
+/// * `Error::InvalidInput` if the input has odd length or contains a zero count
+///
+/// # Performance
+/// * Time: O(n) in the size of the output
+/// * Space: O(n) in the size of the output
+pub fn decode(encoded: &[u8]) -> Result<Vec<u8>> {
+    if !encoded.len().is_multiple_of(2) {
+        return Err(Error::invalid_input(
+            "Run-length data must consist of (count, byte) pairs",
+        ));
+    }
+
+    let mut decoded = Vec::new();
+    for pair in encoded.chunks_exact(2) {
+        let (count, byte) = (pair[0], pair[1]);
+        if count == 0 {
+            return Err(Error::invalid_input("Run-length count must be positive"));
+        }
+        decoded.extend(std::iter::repeat_n(byte, count as usize));
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert!(encode(&[]).is_empty());
+        assert!(decode(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_runs() {
+        assert_eq!(encode(b"x"), vec![1, b'x']);
+        assert_eq!(encode(b"aabbbaa"), vec![2, b'a', 3, b'b', 2, b'a']);
+    }
+
+    #[test]
+    fn test_long_runs_are_split() {
+        let data = vec![7u8; 600];
+        assert_eq!(encode(&data), vec![255, 7, 255, 7, 90, 7]);
+        assert_eq!(decode(&encode(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_repetitive_input_shrinks() {
+        let data: Vec<u8> = (0..10).flat_map(|b| vec![b; 100]).collect();
+        let encoded = encode(&data);
+        assert_eq!(encoded.len(), 20);
+        assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_round_trip_arbitrary_bytes() {
+        let data: Vec<u8> = (0..2_000u32).map(|i| (i * 7919 % 251) as u8).collect();
+        assert_eq!(decode(&encode(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(decode(&[3]), Err(Error::InvalidInput(_))));
+        assert!(matches!(decode(&[0, b'a']), Err(Error::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod kadane;
+
+// Re-export numeric algorithms
+pub use kadane::max_subarray;
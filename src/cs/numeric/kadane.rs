@@ -0,0 +1,128 @@
+use crate::cs::error::{Error, Result};
+use std::ops::Range;
+
+/// Finds the contiguous subarray with the largest sum using Kadane's algorithm.
+///
+/// # Arguments
+/// * `slice` - The values to scan
+///
+/// # Returns
+/// * `Result<(f64, Range<usize>)>` - The maximum subarray sum and the index range
+///   achieving it. If every element is negative, this is the single largest element.
+///   When several ranges achieve the maximum, the one that ends first is returned.
+///   An empty slice yields `(0.0, 0..0)`.
+///
+/// # Errors
+/// * `Error::InvalidInput` if any value is NaN or infinite, since a NaN running sum
+///   can never be compared against the best sum found so far
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::numeric::kadane;
+/// #
+/// let (sum, range) = kadane::max_subarray(&[-2.0, 1.0, -3.0, 4.0, -1.0, 2.0, 1.0, -5.0, 4.0]).unwrap();
+/// assert_eq!(sum, 6.0);
+/// assert_eq!(range, 3..7);
+/// ```
+///
+/// # Performance
+/// * Time: O(n)
+/// * Space: O(1)
+pub fn max_subarray(slice: &[f64]) -> Result<(f64, Range<usize>)> {
+    if let Some(position) = slice.iter().position(|v| !v.is_finite()) {
+        return Err(Error::invalid_input(format!(
+            "values must be finite, got {} at index {}",
+            slice[position], position
+        )));
+    }
+
+    let Some(&first) = slice.first() else {
+        return Ok((0.0, 0..0));
+    };
+
+    let mut best = first;
+    let mut best_range = 0..1;
+    let mut current = first;
+    let mut current_start = 0;
+
+    for (i, &value) in slice.iter().enumerate().skip(1) {
+        // A negative running sum can only hurt, so start a new subarray here
+        if current < 0.0 {
+            current = value;
+            current_start = i;
+        } else {
+            current += value;
+        }
+
+        if current > best {
+            best = current;
+            best_range = current_start..i + 1;
+        }
+    }
+
+    Ok((best, best_range))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// O(n²) reference returning the best sum over all non-empty ranges
+    fn brute_force(slice: &[f64]) -> f64 {
+        let mut best = f64::NEG_INFINITY;
+        for start in 0..slice.len() {
+            let mut sum = 0.0;
+            for &value in &slice[start..] {
+                sum += value;
+                best = best.max(sum);
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_empty_slice() {
+        assert_eq!(max_subarray(&[]).unwrap(), (0.0, 0..0));
+    }
+
+    #[test]
+    fn test_single_element() {
+        assert_eq!(max_subarray(&[5.0]).unwrap(), (5.0, 0..1));
+        assert_eq!(max_subarray(&[-5.0]).unwrap(), (-5.0, 0..1));
+    }
+
+    #[test]
+    fn test_classic_example() {
+        let data = [-2.0, 1.0, -3.0, 4.0, -1.0, 2.0, 1.0, -5.0, 4.0];
+        assert_eq!(max_subarray(&data).unwrap(), (6.0, 3..7));
+    }
+
+    #[test]
+    fn test_all_negative() {
+        let data = [-3.0, -1.5, -7.0, -2.0];
+        assert_eq!(max_subarray(&data).unwrap(), (-1.5, 1..2));
+    }
+
+    #[test]
+    fn test_all_positive() {
+        let data = [1.0, 2.0, 3.0];
+        assert_eq!(max_subarray(&data).unwrap(), (6.0, 0..3));
+    }
+
+    #[test]
+    fn test_non_finite_values_are_rejected() {
+        for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            for data in [[1.0, bad, 5.0], [bad, 1.0, 5.0], [1.0, 5.0, bad]] {
+                assert!(matches!(max_subarray(&data), Err(Error::InvalidInput(_))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_range_sums_to_result() {
+        let data: Vec<f64> = (0..200).map(|i| ((i * 7919 % 101) as f64) - 50.0).collect();
+        let (sum, range) = max_subarray(&data).unwrap();
+        assert_eq!(sum, brute_force(&data));
+        assert_eq!(data[range].iter().sum::<f64>(), sum);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod fenwick;
+pub mod segment_tree;
+
+// Re-export range query structures
+pub use fenwick::FenwickTree;
+pub use segment_tree::SegmentTree;
@@ -0,0 +1,245 @@
+use crate::cs::error::{Error, Result};
+use num_traits::Zero;
+use std::ops::Sub;
+
+/// A Fenwick (binary indexed) tree maintaining prefix sums under point updates.
+///
+/// Node `i` (1-based) stores the sum of the `i & i.wrapping_neg()` elements ending at
+/// element `i`, so both an update and a prefix query touch O(log n) nodes. It needs
+/// less memory and less code than a [`SegmentTree`](super::SegmentTree), but only
+/// supports invertible operations such as addition.
+///
+/// Prefix and range sums use half-open bounds: `prefix_sum(i)` sums elements `0..i`.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::range_query::FenwickTree;
+/// #
+/// let mut tree = FenwickTree::new(5);
+/// tree.add(0, 3).unwrap();
+/// tree.add(2, 4).unwrap();
+/// tree.add(4, 1).unwrap();
+///
+/// assert_eq!(tree.prefix_sum(3).unwrap(), 7);
+/// assert_eq!(tree.range_sum(2, 5).unwrap(), 5);
+/// ```
+///
+/// # Performance
+/// * `add`, `prefix_sum` and `range_sum`: O(log n)
+/// * Space: O(n)
+///
+/// # Type Requirements
+/// * `T: Copy + Zero + Sub` - Sums start from zero, and range sums subtract prefixes
+#[derive(Debug, Clone)]
+pub struct FenwickTree<T> {
+    /// 1-based node sums; `nodes[0]` is unused
+    nodes: Vec<T>,
+}
+
+impl<T: Copy + Zero + Sub<Output = T>> FenwickTree<T> {
+    /// Creates a tree over `n` elements, all zero.
+    pub fn new(n: usize) -> Self {
+        Self {
+            nodes: vec![T::zero(); n + 1],
+        }
+    }
+
+    /// Creates a tree holding the elements of `values`.
+    ///
+    /// # Performance
+    /// * Time: O(n), by pushing each node's sum into its parent once
+    pub fn from_slice(values: &[T]) -> Self {
+        let mut nodes = Vec::with_capacity(values.len() + 1);
+        nodes.push(T::zero());
+        nodes.extend_from_slice(values);
+
+        for i in 1..nodes.len() {
+            let parent = i + lowest_bit(i);
+            if parent < nodes.len() {
+                nodes[parent] = nodes[parent] + nodes[i];
+            }
+        }
+        Self { nodes }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    /// Returns true if the tree has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to the element at `index`.
+    ///
+    /// # Errors
+    /// * `Error::IndexOutOfBounds` if `index >= len()`
+    pub fn add(&mut self, index: usize, delta: T) -> Result<()> {
+        if index >= self.len() {
+            return Err(Error::IndexOutOfBounds(format!(
+                "index {} out of bounds for Fenwick tree of length {}",
+                index,
+                self.len()
+            )));
+        }
+
+        let mut i = index + 1;
+        while i < self.nodes.len() {
+            self.nodes[i] = self.nodes[i] + delta;
+            i += lowest_bit(i);
+        }
+        Ok(())
+    }
+
+    /// Returns the sum of the first `end` elements, i.e. of `0..end`.
+    ///
+    /// # Errors
+    /// * `Error::IndexOutOfBounds` if `end > len()`
+    pub fn prefix_sum(&self, end: usize) -> Result<T> {
+        if end > self.len() {
+            return Err(Error::IndexOutOfBounds(format!(
+                "prefix end {} out of bounds for Fenwick tree of length {}",
+                end,
+                self.len()
+            )));
+        }
+
+        let mut sum = T::zero();
+        let mut i = end;
+        while i > 0 {
+            sum = sum + self.nodes[i];
+            i -= lowest_bit(i);
+        }
+        Ok(sum)
+    }
+
+    /// Returns the sum of the elements in `lo..hi`.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if `lo > hi`
+    /// * `Error::IndexOutOfBounds` if `hi > len()`
+    pub fn range_sum(&self, lo: usize, hi: usize) -> Result<T> {
+        if lo > hi {
+            return Err(Error::invalid_input(format!(
+                "range start {} is after its end {}",
+                lo, hi
+            )));
+        }
+        Ok(self.prefix_sum(hi)? - self.prefix_sum(lo)?)
+    }
+}
+
+/// Returns the value of the lowest set bit of `i`.
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    /// Counts inversions by scanning right to left and asking how many smaller values
+    /// have already been seen.
+    fn count_inversions(values: &[usize]) -> u64 {
+        let max = values.iter().copied().max().map_or(0, |m| m + 1);
+        let mut seen = FenwickTree::<u64>::new(max);
+        let mut inversions = 0;
+        for &value in values.iter().rev() {
+            inversions += seen.prefix_sum(value).unwrap();
+            seen.add(value, 1).unwrap();
+        }
+        inversions
+    }
+
+    #[test]
+    fn test_new_is_zero() {
+        let tree = FenwickTree::<i64>::new(4);
+        assert_eq!(tree.len(), 4);
+        for end in 0..=4 {
+            assert_eq!(tree.prefix_sum(end).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn test_from_slice_matches_adds() {
+        let values = [3i64, -1, 4, 1, -5, 9, 2, -6, 5];
+        let built = FenwickTree::from_slice(&values);
+        let mut added = FenwickTree::new(values.len());
+        for (i, &v) in values.iter().enumerate() {
+            added.add(i, v).unwrap();
+        }
+        assert_eq!(built.nodes, added.nodes);
+    }
+
+    #[test]
+    fn test_prefix_sums_after_random_updates() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let mut values = vec![0i64; 50];
+        let mut tree = FenwickTree::new(values.len());
+
+        for _ in 0..500 {
+            let index = rng.gen_range(0..values.len());
+            let delta = rng.gen_range(-100..=100);
+            values[index] += delta;
+            tree.add(index, delta).unwrap();
+
+            let mut running = 0;
+            assert_eq!(tree.prefix_sum(0).unwrap(), 0);
+            for (end, &v) in values.iter().enumerate() {
+                running += v;
+                assert_eq!(tree.prefix_sum(end + 1).unwrap(), running);
+            }
+        }
+    }
+
+    #[test]
+    fn test_range_sum() {
+        let values = [2.5f64, 1.0, -3.0, 4.0, 0.5];
+        let tree = FenwickTree::from_slice(&values);
+        assert_eq!(tree.range_sum(1, 4).unwrap(), 2.0);
+        assert_eq!(tree.range_sum(2, 2).unwrap(), 0.0);
+        assert_eq!(tree.range_sum(0, 5).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_inversion_count() {
+        assert_eq!(count_inversions(&[0, 1, 2, 3]), 0);
+        assert_eq!(count_inversions(&[3, 2, 1, 0]), 6);
+        assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+
+        let mut shuffled: Vec<usize> = (0..200).collect();
+        shuffled.shuffle(&mut StdRng::seed_from_u64(5));
+        let brute_force = (0..shuffled.len())
+            .flat_map(|i| (i + 1..shuffled.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| shuffled[i] > shuffled[j])
+            .count() as u64;
+        assert_eq!(count_inversions(&shuffled), brute_force);
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let mut tree = FenwickTree::<i32>::new(3);
+        assert!(matches!(tree.add(3, 1), Err(Error::IndexOutOfBounds(_))));
+        assert!(matches!(
+            tree.prefix_sum(4),
+            Err(Error::IndexOutOfBounds(_))
+        ));
+        assert!(matches!(
+            tree.range_sum(1, 4),
+            Err(Error::IndexOutOfBounds(_))
+        ));
+        assert!(matches!(tree.range_sum(2, 1), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = FenwickTree::<u32>::new(0);
+        assert!(tree.is_empty());
+        assert_eq!(tree.prefix_sum(0).unwrap(), 0);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::cs::error::{Error, Result};
+use std::fmt;
+use std::ops::Range;
+
+/// A segment tree answering range queries under any associative merge operation.
+///
+/// The tree is stored bottom-up in a flat vector: the leaves hold the elements and
+/// each internal node holds the merge of its two children. A query combines the
+/// O(log n) nodes that exactly cover the range, keeping a left and a right
+/// accumulator so non-commutative operations are merged in element order.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::range_query::SegmentTree;
+/// #
+/// let data = [5, 2, 8, 1, 9, 3];
+/// let mut sums = SegmentTree::build(&data, |a: &i64, b: &i64| a + b);
+/// let mut mins = SegmentTree::build(&data, |a: &i64, b: &i64| *a.min(b));
+///
+/// assert_eq!(sums.query(1..4).unwrap(), 11);
+/// assert_eq!(mins.query(0..3).unwrap(), 2);
+///
+/// sums.update(2, 0).unwrap();
+/// mins.update(2, 0).unwrap();
+/// assert_eq!(sums.query(1..4).unwrap(), 3);
+/// assert_eq!(mins.query(0..3).unwrap(), 0);
+/// ```
+///
+/// # Performance
+/// * Build: O(n)
+/// * Update and query: O(log n)
+/// * Space: O(n)
+///
+/// # Type Requirements
+/// * `T: Clone` - Elements are cloned into the tree and into query results
+/// * `F: Fn(&T, &T) -> T` - The merge must be associative
+#[derive(Clone)]
+pub struct SegmentTree<T, F>
+where
+    F: Fn(&T, &T) -> T,
+{
+    /// Number of elements
+    len: usize,
+    /// Node `i` of the 1-based heap layout is stored at `nodes[i - 1]`; the leaves
+    /// are nodes `len..2 * len`
+    nodes: Vec<T>,
+    /// The associative merge operation
+    merge: F,
+}
+
+// Manually implement Debug since the merge closure doesn't implement Debug.
+impl<T: fmt::Debug, F: Fn(&T, &T) -> T> fmt::Debug for SegmentTree<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SegmentTree")
+            .field("len", &self.len)
+            .field("nodes", &self.nodes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> SegmentTree<T, F> {
+    /// Builds a tree over the elements of `slice`, merged with `merge`.
+    ///
+    /// # Arguments
+    /// * `slice` - The initial elements
+    /// * `merge` - An associative operation such as sum, min, max or gcd
+    pub fn build(slice: &[T], merge: F) -> Self {
+        let len = slice.len();
+        let mut nodes = Vec::with_capacity(2 * len);
+        // Placeholders for the len - 1 internal nodes, overwritten below
+        nodes.extend_from_slice(slice.get(1..).unwrap_or_default());
+        nodes.extend_from_slice(slice);
+
+        let mut tree = Self { len, nodes, merge };
+        for i in (1..len).rev() {
+            tree.nodes[i - 1] = tree.merged_children(i);
+        }
+        tree
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the tree holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Replaces the element at `index` and updates every node above it.
+    ///
+    /// # Errors
+    /// * `Error::IndexOutOfBounds` if `index >= len()`
+    pub fn update(&mut self, index: usize, value: T) -> Result<()> {
+        if index >= self.len {
+            return Err(Error::IndexOutOfBounds(format!(
+                "index {} out of bounds for segment tree of length {}",
+                index, self.len
+            )));
+        }
+
+        let mut node = index + self.len;
+        self.nodes[node - 1] = value;
+        while node > 1 {
+            node /= 2;
+            self.nodes[node - 1] = self.merged_children(node);
+        }
+        Ok(())
+    }
+
+    /// Merges the elements in `range`, in order.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if the range is empty, since there is no identity element
+    /// * `Error::IndexOutOfBounds` if the range extends past `len()`
+    pub fn query(&self, range: Range<usize>) -> Result<T> {
+        if range.end > self.len {
+            return Err(Error::IndexOutOfBounds(format!(
+                "range {:?} out of bounds for segment tree of length {}",
+                range, self.len
+            )));
+        }
+        if range.start >= range.end {
+            return Err(Error::invalid_input(format!(
+                "query range {:?} is empty",
+                range
+            )));
+        }
+
+        let mut left: Option<T> = None;
+        let mut right: Option<T> = None;
+        let mut l = range.start + self.len;
+        let mut r = range.end + self.len;
+        while l < r {
+            if l % 2 == 1 {
+                let node = &self.nodes[l - 1];
+                left = Some(match left {
+                    Some(acc) => (self.merge)(&acc, node),
+                    None => node.clone(),
+                });
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                let node = &self.nodes[r - 1];
+                right = Some(match right {
+                    Some(acc) => (self.merge)(node, &acc),
+                    None => node.clone(),
+                });
+            }
+            l /= 2;
+            r /= 2;
+        }
+
+        match (left, right) {
+            (Some(left), Some(right)) => Ok((self.merge)(&left, &right)),
+            (Some(value), None) | (None, Some(value)) => Ok(value),
+            (None, None) => unreachable!("a non-empty range covers at least one node"),
+        }
+    }
+
+    fn merged_children(&self, node: usize) -> T {
+        (self.merge)(&self.nodes[2 * node - 1], &self.nodes[2 * node])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gcd(a: &u64, b: &u64) -> u64 {
+        let (mut a, mut b) = (*a, *b);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    #[test]
+    fn test_sum_and_min_trees() {
+        let data = [4i64, -2, 7, 1, 0, 9, -5, 3];
+        let mut sums = SegmentTree::build(&data, |a: &i64, b: &i64| a + b);
+        let mut mins = SegmentTree::build(&data, |a: &i64, b: &i64| *a.min(b));
+
+        assert_eq!(sums.len(), 8);
+        assert_eq!(sums.query(0..8).unwrap(), 17);
+        assert_eq!(sums.query(2..5).unwrap(), 8);
+        assert_eq!(sums.query(5..6).unwrap(), 9);
+        assert_eq!(mins.query(0..8).unwrap(), -5);
+        assert_eq!(mins.query(0..3).unwrap(), -2);
+        assert_eq!(mins.query(2..6).unwrap(), 0);
+
+        sums.update(6, 10).unwrap();
+        mins.update(6, 10).unwrap();
+        assert_eq!(sums.query(0..8).unwrap(), 32);
+        assert_eq!(sums.query(6..8).unwrap(), 13);
+        assert_eq!(mins.query(0..8).unwrap(), -2);
+        assert_eq!(mins.query(5..8).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_max_and_gcd() {
+        let data = [12u64, 18, 24, 36, 10];
+        let maxes = SegmentTree::build(&data, |a: &u64, b: &u64| *a.max(b));
+        let mut gcds = SegmentTree::build(&data, gcd);
+
+        assert_eq!(maxes.query(0..3).unwrap(), 24);
+        assert_eq!(gcds.query(0..4).unwrap(), 6);
+        assert_eq!(gcds.query(0..5).unwrap(), 2);
+
+        gcds.update(1, 6).unwrap();
+        assert_eq!(gcds.query(0..4).unwrap(), 6);
+        assert_eq!(gcds.query(3..5).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_non_commutative_merge_preserves_order() {
+        let words: Vec<String> = ["a", "b", "c", "d", "e", "f", "g"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut tree = SegmentTree::build(&words, |a: &String, b: &String| format!("{}{}", a, b));
+
+        for start in 0..words.len() {
+            for end in start + 1..=words.len() {
+                assert_eq!(tree.query(start..end).unwrap(), words[start..end].concat());
+            }
+        }
+
+        tree.update(3, "X".to_string()).unwrap();
+        assert_eq!(tree.query(1..6).unwrap(), "bcXef");
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut data: Vec<i64> = (0..37).map(|i| (i * 7919 % 101) - 50).collect();
+        let mut tree = SegmentTree::build(&data, |a: &i64, b: &i64| *a.min(b));
+
+        for step in 0..50usize {
+            let index = step * 13 % data.len();
+            let value = (step as i64 * 31 % 97) - 48;
+            data[index] = value;
+            tree.update(index, value).unwrap();
+
+            for start in (0..data.len()).step_by(5) {
+                for end in (start + 1..=data.len()).step_by(3) {
+                    let expected = *data[start..end].iter().min().unwrap();
+                    assert_eq!(tree.query(start..end).unwrap(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_element() {
+        let mut tree = SegmentTree::build(&[42], |a: &i32, b: &i32| a + b);
+        assert_eq!(tree.query(0..1).unwrap(), 42);
+        tree.update(0, 7).unwrap();
+        assert_eq!(tree.query(0..1).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = SegmentTree::build(&[], |a: &i32, b: &i32| a + b);
+        assert!(tree.is_empty());
+        assert!(matches!(tree.query(0..1), Err(Error::IndexOutOfBounds(_))));
+    }
+
+    #[test]
+    fn test_invalid_ranges() {
+        let mut tree = SegmentTree::build(&[1, 2, 3], |a: &i32, b: &i32| a + b);
+        assert!(matches!(tree.query(1..4), Err(Error::IndexOutOfBounds(_))));
+        assert!(matches!(tree.query(2..2), Err(Error::InvalidInput(_))));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = tree.query(2..1);
+        assert!(matches!(reversed, Err(Error::InvalidInput(_))));
+        assert!(matches!(tree.update(3, 0), Err(Error::IndexOutOfBounds(_))));
+    }
+}
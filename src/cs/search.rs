@@ -1,5 +1,6 @@
 pub mod bfs;
 pub mod binary;
+pub mod cycle;
 pub mod dfs;
 pub mod exponential;
 pub mod fibonacci;
@@ -7,6 +8,7 @@ pub mod interpolation;
 pub mod jump;
 pub mod linear;
 pub mod sublist;
+pub mod subsequence;
 pub mod ternary;
 
 // Re-export graph types
@@ -15,10 +17,12 @@ pub use dfs::Graph as DfsGraph;
 
 // Re-export search functions
 pub use binary::search as binary_search;
+pub use cycle::floyd_cycle;
 pub use exponential::search as exponential_search;
 pub use fibonacci::search as fibonacci_search;
 pub use interpolation::search as interpolation_search;
 pub use jump::search as jump_search;
 pub use linear::search as linear_search;
 pub use sublist::{search as sublist_search, search_kmp as sublist_search_kmp};
+pub use subsequence::longest_increasing_subsequence;
 pub use ternary::search as ternary_search;
@@ -189,7 +189,7 @@ mod tests {
         let mut graph = Graph::new();
         graph.add_edge(1, 2);
         graph.add_edge(2, 3);
-        assert!(matches!(graph.search(&1, &4).unwrap(), None));
+        assert!(graph.search(&1, &4).unwrap().is_none());
     }
 
     #[test]
@@ -235,7 +235,7 @@ mod tests {
         graph.add_edge(1, 2);
         graph.add_edge(3, 4);
 
-        assert!(matches!(graph.search(&1, &4).unwrap(), None));
+        assert!(graph.search(&1, &4).unwrap().is_none());
     }
 
     #[test]
@@ -0,0 +1,135 @@
+/// Detects a cycle in the sequence `start, next(start), next(next(start)), ...`
+/// using Floyd's tortoise-and-hare algorithm.
+///
+/// The sequence models a functional graph or a linked structure: `next` maps a
+/// node to its successor, returning `None` when the node has no successor (the
+/// end of an acyclic list).
+///
+/// # Arguments
+/// * `start` - The first element of the sequence
+/// * `next` - Maps an element to its successor, or `None` at the end of the sequence
+///
+/// # Returns
+/// * `Some((mu, lambda))` - `mu` is the index of the first element on the cycle and
+///   `lambda` is the length of the cycle
+/// * `None` - The sequence terminates, so it contains no cycle
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::search::cycle;
+/// #
+/// // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+/// let next = |&x: &u32| Some(if x == 4 { 2 } else { x + 1 });
+/// assert_eq!(cycle::floyd_cycle(0, next), Some((2, 3)));
+///
+/// // 0 -> 1 -> 2 -> 3 (end)
+/// let next = |&x: &u32| if x < 3 { Some(x + 1) } else { None };
+/// assert_eq!(cycle::floyd_cycle(0, next), None);
+/// ```
+///
+/// # Performance
+/// * Time: O(mu + lambda) evaluations of `next`
+/// * Space: O(1)
+///
+/// # Type Requirements
+/// * `T: PartialEq + Clone` - Elements must support equality comparison, and the start
+///   element is cloned once to restart the tortoise
+pub fn floyd_cycle<T, F>(start: T, next: F) -> Option<(usize, usize)>
+where
+    T: PartialEq + Clone,
+    F: Fn(&T) -> Option<T>,
+{
+    // Phase 1: the hare moves two steps for every step of the tortoise until they
+    // meet somewhere on the cycle, or the hare runs off the end of the sequence.
+    let mut tortoise = next(&start)?;
+    let mut hare = next(&tortoise)?;
+    while tortoise != hare {
+        tortoise = next(&tortoise)?;
+        hare = next(&next(&hare)?)?;
+    }
+
+    // Phase 2: restart the tortoise from the beginning; moving both one step at a
+    // time, they meet at the first element of the cycle.
+    let mut mu = 0;
+    let mut tortoise = start;
+    while tortoise != hare {
+        tortoise = next(&tortoise)?;
+        hare = next(&hare)?;
+        mu += 1;
+    }
+
+    // Phase 3: walk the hare around the cycle once to measure its length.
+    let mut lambda = 1;
+    let mut hare = next(&tortoise)?;
+    while tortoise != hare {
+        hare = next(&hare)?;
+        lambda += 1;
+    }
+
+    Some((mu, lambda))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pure_cycle() {
+        let next = |&x: &u32| Some((x + 1) % 5);
+        assert_eq!(floyd_cycle(0, next), Some((0, 5)));
+    }
+
+    #[test]
+    fn test_rho_shape() {
+        // Tail 0 -> 1 -> 2, then cycle 3 -> 4 -> 5 -> 6 -> 3
+        let next = |&x: &u32| Some(if x == 6 { 3 } else { x + 1 });
+        assert_eq!(floyd_cycle(0, next), Some((3, 4)));
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let next = |&x: &u32| Some(if x == 2 { 2 } else { x + 1 });
+        assert_eq!(floyd_cycle(0, next), Some((2, 1)));
+        assert_eq!(floyd_cycle(2, next), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_acyclic_sequence() {
+        let next = |&x: &u32| if x < 10 { Some(x + 1) } else { None };
+        assert_eq!(floyd_cycle(0, next), None);
+
+        // A single node with no successor
+        assert_eq!(floyd_cycle(10, next), None);
+    }
+
+    #[test]
+    fn test_linked_list() {
+        // Linked list stored as successor indices, with a back edge from 4 to 1
+        let succ = [Some(1), Some(2), Some(3), Some(4), Some(1)];
+        assert_eq!(floyd_cycle(0, |&i: &usize| succ[i]), Some((1, 4)));
+
+        let succ = [Some(1), Some(2), Some(3), Some(4), None];
+        assert_eq!(floyd_cycle(0, |&i: &usize| succ[i]), None);
+    }
+
+    #[test]
+    fn test_iterated_function() {
+        // x -> x^2 + 1 mod 255 starting from 3
+        let f = |&x: &u64| Some((x * x + 1) % 255);
+
+        // Brute force reference: record the first index each value appears at
+        let mut seen = std::collections::HashMap::new();
+        let mut x = 3;
+        let mut i = 0;
+        let expected = loop {
+            if let Some(&first) = seen.get(&x) {
+                break (first, i - first);
+            }
+            seen.insert(x, i);
+            x = f(&x).unwrap();
+            i += 1;
+        };
+
+        assert_eq!(floyd_cycle(3, f), Some(expected));
+    }
+}
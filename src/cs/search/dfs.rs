@@ -185,8 +185,8 @@ mod tests {
         let mut graph = Graph::new();
         graph.add_edge(1, 2);
         graph.add_edge(2, 3);
-        assert!(matches!(graph.search(&1, &4).unwrap(), None));
-        assert!(matches!(graph.search_iterative(&1, &4).unwrap(), None));
+        assert!(graph.search(&1, &4).unwrap().is_none());
+        assert!(graph.search_iterative(&1, &4).unwrap().is_none());
     }
 
     #[test]
@@ -242,8 +242,8 @@ mod tests {
         graph.add_edge(1, 2);
         graph.add_edge(3, 4);
 
-        assert!(matches!(graph.search(&1, &4).unwrap(), None));
-        assert!(matches!(graph.search_iterative(&1, &4).unwrap(), None));
+        assert!(graph.search(&1, &4).unwrap().is_none());
+        assert!(graph.search_iterative(&1, &4).unwrap().is_none());
     }
 
     #[test]
@@ -102,7 +102,7 @@ mod tests {
     #[test]
     fn test_empty_slice() {
         let data: Vec<i32> = vec![];
-        assert!(matches!(search(&data, &5).unwrap(), None));
+        assert!(search(&data, &5).unwrap().is_none());
     }
 
     #[test]
@@ -114,7 +114,7 @@ mod tests {
     #[test]
     fn test_single_element_not_found() {
         let data = vec![5];
-        assert!(matches!(search(&data, &3).unwrap(), None));
+        assert!(search(&data, &3).unwrap().is_none());
     }
 
     #[test]
@@ -138,7 +138,7 @@ mod tests {
     #[test]
     fn test_multiple_elements_not_found() {
         let data = vec![1, 2, 3, 4, 5];
-        assert!(matches!(search(&data, &6).unwrap(), None));
+        assert!(search(&data, &6).unwrap().is_none());
     }
 
     #[test]
@@ -146,7 +146,7 @@ mod tests {
         let data = vec![1, 2, 2, 2, 3, 4];
         // Should find any occurrence of the duplicate value
         let result = search(&data, &2).unwrap().unwrap();
-        assert!(result >= 1 && result <= 3);
+        assert!((1..=3).contains(&result));
     }
 
     #[test]
@@ -159,14 +159,14 @@ mod tests {
     fn test_large_sorted_dataset() {
         let data: Vec<i32> = (0..10_000).collect();
         assert!(matches!(search(&data, &5000).unwrap(), Some(5000)));
-        assert!(matches!(search(&data, &10_000).unwrap(), None));
+        assert!(search(&data, &10_000).unwrap().is_none());
     }
 
     #[test]
     fn test_with_strings() {
         let data = vec!["apple", "banana", "orange", "pear"];
         assert!(matches!(search(&data, &"orange").unwrap(), Some(2)));
-        assert!(matches!(search(&data, &"grape").unwrap(), None));
+        assert!(search(&data, &"grape").unwrap().is_none());
     }
 
     #[test]
@@ -107,7 +107,7 @@ mod tests {
     #[test]
     fn test_empty_slice() {
         let data: Vec<i32> = vec![];
-        assert!(matches!(search(&data, &5).unwrap(), None));
+        assert!(search(&data, &5).unwrap().is_none());
     }
 
     #[test]
@@ -119,7 +119,7 @@ mod tests {
     #[test]
     fn test_single_element_not_found() {
         let data = vec![5];
-        assert!(matches!(search(&data, &3).unwrap(), None));
+        assert!(search(&data, &3).unwrap().is_none());
     }
 
     #[test]
@@ -143,7 +143,7 @@ mod tests {
     #[test]
     fn test_multiple_elements_not_found() {
         let data = vec![1, 2, 3, 4, 5];
-        assert!(matches!(search(&data, &6).unwrap(), None));
+        assert!(search(&data, &6).unwrap().is_none());
     }
 
     #[test]
@@ -151,7 +151,7 @@ mod tests {
         let data = vec![1, 2, 2, 2, 3, 4];
         // Should find any occurrence of the duplicate value
         let result = search(&data, &2).unwrap().unwrap();
-        assert!(result >= 1 && result <= 3);
+        assert!((1..=3).contains(&result));
     }
 
     #[test]
@@ -165,28 +165,28 @@ mod tests {
         let data: Vec<i32> = (0..100).collect();
         assert!(matches!(search(&data, &50).unwrap(), Some(50)));
         assert!(matches!(search(&data, &99).unwrap(), Some(99)));
-        assert!(matches!(search(&data, &100).unwrap(), None));
+        assert!(search(&data, &100).unwrap().is_none());
     }
 
     #[test]
     fn test_non_uniform_distribution() {
         let data = vec![1, 10, 100, 1000, 10000];
         assert!(matches!(search(&data, &100).unwrap(), Some(2)));
-        assert!(matches!(search(&data, &500).unwrap(), None));
+        assert!(search(&data, &500).unwrap().is_none());
     }
 
     #[test]
     fn test_large_uniform_dataset() {
         let data: Vec<i32> = (0..10_000).collect();
         assert!(matches!(search(&data, &5000).unwrap(), Some(5000)));
-        assert!(matches!(search(&data, &10_000).unwrap(), None));
+        assert!(search(&data, &10_000).unwrap().is_none());
     }
 
     #[test]
     fn test_floating_point() {
         let data = vec![1, 2, 3, 4, 5];
         assert!(matches!(search(&data, &3).unwrap(), Some(2)));
-        assert!(matches!(search(&data, &6).unwrap(), None));
+        assert!(search(&data, &6).unwrap().is_none());
     }
 
     #[test]
@@ -200,13 +200,13 @@ mod tests {
     #[test]
     fn test_equal_values_target_not_found() {
         let data = vec![5, 5, 5, 5, 5];
-        assert!(matches!(search(&data, &3).unwrap(), None));
+        assert!(search(&data, &3).unwrap().is_none());
     }
 
     #[test]
     fn test_pos_greater_than_high() {
         let data = vec![1, 2, 1000000];
-        assert!(matches!(search(&data, &999999).unwrap(), None));
+        assert!(search(&data, &999999).unwrap().is_none());
     }
 
     #[test]
@@ -237,12 +237,12 @@ mod tests {
     #[test]
     fn test_interpolation_out_of_bounds() {
         let data = vec![1, 2, 1000000];
-        assert!(matches!(search(&data, &999999).unwrap(), None));
+        assert!(search(&data, &999999).unwrap().is_none());
     }
 
     #[test]
     fn test_target_less_than_first() {
         let data = vec![5, 10, 15, 20];
-        assert!(matches!(search(&data, &1).unwrap(), None));
+        assert!(search(&data, &1).unwrap().is_none());
     }
 }
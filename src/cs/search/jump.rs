@@ -88,7 +88,7 @@ mod tests {
     #[test]
     fn test_empty_slice() {
         let data: Vec<i32> = vec![];
-        assert!(matches!(search(&data, &5).unwrap(), None));
+        assert!(search(&data, &5).unwrap().is_none());
     }
 
     #[test]
@@ -100,7 +100,7 @@ mod tests {
     #[test]
     fn test_single_element_not_found() {
         let data = vec![5];
-        assert!(matches!(search(&data, &3).unwrap(), None));
+        assert!(search(&data, &3).unwrap().is_none());
     }
 
     #[test]
@@ -124,7 +124,7 @@ mod tests {
     #[test]
     fn test_multiple_elements_not_found() {
         let data = vec![1, 2, 3, 4, 5];
-        assert!(matches!(search(&data, &6).unwrap(), None));
+        assert!(search(&data, &6).unwrap().is_none());
     }
 
     #[test]
@@ -132,7 +132,7 @@ mod tests {
         let data = vec![1, 2, 2, 2, 3, 4];
         // Should find any occurrence of the duplicate value
         let result = search(&data, &2).unwrap().unwrap();
-        assert!(result >= 1 && result <= 3);
+        assert!((1..=3).contains(&result));
     }
 
     #[test]
@@ -145,14 +145,14 @@ mod tests {
     fn test_large_sorted_dataset() {
         let data: Vec<i32> = (0..10_000).collect();
         assert!(matches!(search(&data, &5000).unwrap(), Some(5000)));
-        assert!(matches!(search(&data, &10_000).unwrap(), None));
+        assert!(search(&data, &10_000).unwrap().is_none());
     }
 
     #[test]
     fn test_with_strings() {
         let data = vec!["apple", "banana", "orange", "pear"];
         assert!(matches!(search(&data, &"orange").unwrap(), Some(2)));
-        assert!(matches!(search(&data, &"grape").unwrap(), None));
+        assert!(search(&data, &"grape").unwrap().is_none());
     }
 
     #[test]
@@ -178,37 +178,37 @@ mod tests {
     #[test]
     fn test_jump_past_end() {
         let data = vec![1, 3, 5, 7, 9, 11, 13, 15, 17];
-        assert!(matches!(search(&data, &20).unwrap(), None));
+        assert!(search(&data, &20).unwrap().is_none());
     }
 
     #[test]
     fn test_step_adjustment() {
         let data = vec![1, 3, 5, 7, 9, 11];
-        assert!(matches!(search(&data, &10).unwrap(), None));
+        assert!(search(&data, &10).unwrap().is_none());
     }
 
     #[test]
     fn test_break_on_greater() {
         let data = vec![1, 3, 5, 7, 9, 11];
-        assert!(matches!(search(&data, &4).unwrap(), None));
+        assert!(search(&data, &4).unwrap().is_none());
     }
 
     #[test]
     fn test_prev_exceeds_len() {
         let data = vec![1, 3, 5, 7, 9, 11, 13, 15, 17];
         let target = 100; // This will cause prev to exceed data.len()
-        assert!(matches!(search(&data, &target).unwrap(), None));
+        assert!(search(&data, &target).unwrap().is_none());
     }
 
     #[test]
     fn test_step_adjustment_with_target() {
         let data = vec![1, 3, 5, 7, 9, 11, 13, 15];
-        assert!(matches!(search(&data, &14).unwrap(), None));
+        assert!(search(&data, &14).unwrap().is_none());
     }
 
     #[test]
     fn test_break_in_linear_search() {
         let data = vec![1, 3, 5, 7, 9, 11, 13, 15];
-        assert!(matches!(search(&data, &6).unwrap(), None));
+        assert!(search(&data, &6).unwrap().is_none());
     }
 }
@@ -71,7 +71,7 @@ mod tests {
     #[test]
     fn test_empty_slice() {
         let data: Vec<i32> = vec![];
-        assert!(matches!(search(&data, &5).unwrap(), None));
+        assert!(search(&data, &5).unwrap().is_none());
     }
 
     #[test]
@@ -83,7 +83,7 @@ mod tests {
     #[test]
     fn test_single_element_not_found() {
         let data = vec![5];
-        assert!(matches!(search(&data, &3).unwrap(), None));
+        assert!(search(&data, &3).unwrap().is_none());
     }
 
     #[test]
@@ -107,7 +107,7 @@ mod tests {
     #[test]
     fn test_multiple_elements_not_found() {
         let data = vec![1, 2, 3, 4, 5];
-        assert!(matches!(search(&data, &6).unwrap(), None));
+        assert!(search(&data, &6).unwrap().is_none());
     }
 
     #[test]
@@ -120,7 +120,7 @@ mod tests {
     fn test_with_strings() {
         let data = vec!["apple", "banana", "orange"];
         assert!(matches!(search(&data, &"banana").unwrap(), Some(1)));
-        assert!(matches!(search(&data, &"grape").unwrap(), None));
+        assert!(search(&data, &"grape").unwrap().is_none());
     }
 
     #[test]
@@ -132,10 +132,9 @@ mod tests {
         let expected_pos = PARALLEL_THRESHOLD + 50;
         let result = search(&data, &target).unwrap();
         assert!(matches!(result, Some(pos) if pos == expected_pos));
-        assert!(matches!(
-            search(&data, &(PARALLEL_THRESHOLD as i32 + 200)).unwrap(),
-            None
-        ));
+        assert!(search(&data, &(PARALLEL_THRESHOLD as i32 + 200))
+            .unwrap()
+            .is_none());
     }
 
     #[test]
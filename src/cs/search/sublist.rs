@@ -159,8 +159,8 @@ mod tests {
     fn test_empty_data() {
         let data: Vec<i32> = vec![];
         let pattern = vec![1, 2];
-        assert!(matches!(search(&data, &pattern).unwrap(), None));
-        assert!(matches!(search_kmp(&data, &pattern).unwrap(), None));
+        assert!(search(&data, &pattern).unwrap().is_none());
+        assert!(search_kmp(&data, &pattern).unwrap().is_none());
     }
 
     #[test]
@@ -181,8 +181,8 @@ mod tests {
     fn test_pattern_longer_than_data() {
         let data = vec![1, 2];
         let pattern = vec![1, 2, 3];
-        assert!(matches!(search(&data, &pattern).unwrap(), None));
-        assert!(matches!(search_kmp(&data, &pattern).unwrap(), None));
+        assert!(search(&data, &pattern).unwrap().is_none());
+        assert!(search_kmp(&data, &pattern).unwrap().is_none());
     }
 
     #[test]
@@ -213,8 +213,8 @@ mod tests {
     fn test_no_match() {
         let data = vec![1, 2, 3, 4, 5];
         let pattern = vec![2, 4];
-        assert!(matches!(search(&data, &pattern).unwrap(), None));
-        assert!(matches!(search_kmp(&data, &pattern).unwrap(), None));
+        assert!(search(&data, &pattern).unwrap().is_none());
+        assert!(search_kmp(&data, &pattern).unwrap().is_none());
     }
 
     #[test]
@@ -0,0 +1,137 @@
+/// Finds a longest strictly increasing subsequence of a sequence.
+///
+/// Uses the patience-sorting approach: `tails[l]` holds the index of the smallest
+/// element that ends an increasing subsequence of length `l + 1`. Each element is
+/// placed by binary search over `tails`, and a predecessor array records how to
+/// walk back from the end of the longest subsequence.
+///
+/// # Arguments
+/// * `seq` - The sequence to search
+///
+/// # Returns
+/// * A longest strictly increasing subsequence, in order. When several exist, one
+///   ending in the smallest possible final element is returned. An empty input
+///   yields an empty vector.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::search::subsequence;
+/// #
+/// let lis = subsequence::longest_increasing_subsequence(&[10, 9, 2, 5, 3, 7, 101, 18]);
+/// assert_eq!(lis, vec![2, 3, 7, 18]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n log n)
+/// * Space: O(n)
+///
+/// # Type Requirements
+/// * `T: Ord + Clone` - Elements must be totally ordered and are cloned into the result
+pub fn longest_increasing_subsequence<T: Ord + Clone>(seq: &[T]) -> Vec<T> {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessor: Vec<Option<usize>> = vec![None; seq.len()];
+
+    for (i, item) in seq.iter().enumerate() {
+        // First length whose tail is not smaller than the current element
+        let len = tails.partition_point(|&t| seq[t] < *item);
+        if len > 0 {
+            predecessor[i] = Some(tails[len - 1]);
+        }
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut result = Vec::with_capacity(tails.len());
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        result.push(seq[i].clone());
+        current = predecessor[i];
+    }
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_strictly_increasing<T: Ord>(seq: &[T]) -> bool {
+        seq.windows(2).all(|w| w[0] < w[1])
+    }
+
+    fn is_subsequence<T: PartialEq>(sub: &[T], seq: &[T]) -> bool {
+        let mut it = seq.iter();
+        sub.iter().all(|x| it.any(|y| y == x))
+    }
+
+    /// O(n²) dynamic-programming reference for the LIS length
+    fn lis_length_reference<T: Ord>(seq: &[T]) -> usize {
+        let mut best = vec![1; seq.len()];
+        for i in 0..seq.len() {
+            for j in 0..i {
+                if seq[j] < seq[i] {
+                    best[i] = best[i].max(best[j] + 1);
+                }
+            }
+        }
+        best.into_iter().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(longest_increasing_subsequence::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_single_element() {
+        assert_eq!(longest_increasing_subsequence(&[42]), vec![42]);
+    }
+
+    #[test]
+    fn test_classic_example() {
+        let seq = [10, 9, 2, 5, 3, 7, 101, 18];
+        let lis = longest_increasing_subsequence(&seq);
+        assert_eq!(lis.len(), 4);
+        assert!(is_strictly_increasing(&lis));
+        assert!(is_subsequence(&lis, &seq));
+    }
+
+    #[test]
+    fn test_strictly_decreasing() {
+        let seq = [9, 7, 5, 3, 1];
+        assert_eq!(longest_increasing_subsequence(&seq).len(), 1);
+    }
+
+    #[test]
+    fn test_already_sorted() {
+        let seq: Vec<i32> = (0..20).collect();
+        assert_eq!(longest_increasing_subsequence(&seq), seq);
+    }
+
+    #[test]
+    fn test_duplicates_are_not_increasing() {
+        assert_eq!(longest_increasing_subsequence(&[3, 3, 3]), vec![3]);
+        assert_eq!(longest_increasing_subsequence(&[1, 2, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_strings() {
+        let seq = ["b", "a", "c", "b", "d"];
+        let lis = longest_increasing_subsequence(&seq);
+        assert_eq!(lis.len(), 3);
+        assert!(is_strictly_increasing(&lis));
+        assert!(is_subsequence(&lis, &seq));
+    }
+
+    #[test]
+    fn test_matches_quadratic_reference() {
+        let seq: Vec<i32> = (0..300).map(|i| (i * 7919 % 211) - 100).collect();
+        let lis = longest_increasing_subsequence(&seq);
+        assert_eq!(lis.len(), lis_length_reference(&seq));
+        assert!(is_strictly_increasing(&lis));
+        assert!(is_subsequence(&lis, &seq));
+    }
+}
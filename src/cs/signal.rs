@@ -0,0 +1,4 @@
+pub mod convolution;
+
+// Re-export signal processing functions
+pub use convolution::{convolve1d, correlate1d, ConvMode};
@@ -0,0 +1,208 @@
+/// Which part of the full convolution to return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConvMode {
+    /// Every overlap of the inputs, `n + m - 1` values
+    #[default]
+    Full,
+    /// The centre of the full output, as long as the longer input
+    Same,
+    /// Only positions where the inputs overlap completely, `max(n, m) - min(n, m) + 1`
+    /// values
+    Valid,
+}
+
+/// Computes the discrete convolution of a signal with a kernel.
+///
+/// Element `k` of the full output is `Σᵢ signal[i] · kernel[k - i]`. The kernel is
+/// flipped as it slides along the signal. Convolution is commutative, so in `Valid`
+/// mode a kernel longer than the signal gives the same result as swapping them.
+///
+/// # Arguments
+/// * `signal` - The input sequence
+/// * `kernel` - The filter to apply, e.g. `[1.0 / 3.0; 3]` for a moving average
+/// * `mode` - How much of the full output to keep
+///
+/// # Returns
+/// * The convolution, trimmed according to `mode`. An empty vector if either input
+///   is empty.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::signal::convolution::{self, ConvMode};
+/// #
+/// let signal = [1.0, 2.0, 3.0];
+/// let kernel = [0.0, 1.0, 0.5];
+/// assert_eq!(convolution::convolve1d(&signal, &kernel, ConvMode::Full), vec![0.0, 1.0, 2.5, 4.0, 1.5]);
+/// assert_eq!(convolution::convolve1d(&signal, &kernel, ConvMode::Same), vec![1.0, 2.5, 4.0]);
+/// assert_eq!(convolution::convolve1d(&signal, &kernel, ConvMode::Valid), vec![2.5]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n × m)
+/// * Space: O(n + m)
+pub fn convolve1d(signal: &[f64], kernel: &[f64], mode: ConvMode) -> Vec<f64> {
+    if signal.is_empty() || kernel.is_empty() {
+        return Vec::new();
+    }
+
+    let mut full = vec![0.0; signal.len() + kernel.len() - 1];
+    for (i, &s) in signal.iter().enumerate() {
+        for (j, &k) in kernel.iter().enumerate() {
+            full[i + j] += s * k;
+        }
+    }
+
+    let shorter = signal.len().min(kernel.len());
+    let longer = signal.len().max(kernel.len());
+    let (start, len) = match mode {
+        ConvMode::Full => return full,
+        ConvMode::Same => ((shorter - 1) / 2, longer),
+        ConvMode::Valid => (shorter - 1, longer - shorter + 1),
+    };
+    full.drain(..start);
+    full.truncate(len);
+    full
+}
+
+/// Computes the cross-correlation of a signal with a kernel.
+///
+/// Correlation slides the kernel along the signal without flipping it, so it equals
+/// [`convolve1d`] with the kernel reversed. Element `k` of the full output is
+/// `Σᵢ signal[i] · kernel[i + m - 1 - k]`, where `m` is the kernel length.
+///
+/// # Arguments
+/// * `signal` - The input sequence
+/// * `kernel` - The template to match against the signal
+/// * `mode` - How much of the full output to keep
+///
+/// # Returns
+/// * The cross-correlation, trimmed according to `mode`. An empty vector if either
+///   input is empty.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::signal::convolution::{self, ConvMode};
+/// #
+/// let signal = [1.0, 2.0, 3.0];
+/// let kernel = [0.0, 1.0, 0.5];
+/// assert_eq!(convolution::correlate1d(&signal, &kernel, ConvMode::Full), vec![0.5, 2.0, 3.5, 3.0, 0.0]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n × m)
+/// * Space: O(n + m)
+pub fn correlate1d(signal: &[f64], kernel: &[f64], mode: ConvMode) -> Vec<f64> {
+    let reversed: Vec<f64> = kernel.iter().rev().copied().collect();
+    convolve1d(signal, &reversed, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [ConvMode; 3] = [ConvMode::Full, ConvMode::Same, ConvMode::Valid];
+
+    #[test]
+    fn test_convolve_modes() {
+        let signal = [1.0, 2.0, 3.0];
+        let kernel = [0.0, 1.0, 0.5];
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Full),
+            vec![0.0, 1.0, 2.5, 4.0, 1.5]
+        );
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Same),
+            vec![1.0, 2.5, 4.0]
+        );
+        assert_eq!(convolve1d(&signal, &kernel, ConvMode::Valid), vec![2.5]);
+    }
+
+    #[test]
+    fn test_convolve_moving_sum() {
+        let signal = [1.0, 2.0, 3.0, 4.0];
+        let kernel = [1.0, 1.0];
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Full),
+            vec![1.0, 3.0, 5.0, 7.0, 4.0]
+        );
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Same),
+            vec![1.0, 3.0, 5.0, 7.0]
+        );
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Valid),
+            vec![3.0, 5.0, 7.0]
+        );
+    }
+
+    #[test]
+    fn test_convolve_kernel_longer_than_signal() {
+        let signal = [1.0, 2.0];
+        let kernel = [1.0, 2.0, 3.0];
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Full),
+            vec![1.0, 4.0, 7.0, 6.0]
+        );
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Same),
+            vec![1.0, 4.0, 7.0]
+        );
+        assert_eq!(
+            convolve1d(&signal, &kernel, ConvMode::Valid),
+            vec![4.0, 7.0]
+        );
+
+        // Convolution is commutative in every mode
+        for mode in MODES {
+            assert_eq!(
+                convolve1d(&signal, &kernel, mode),
+                convolve1d(&kernel, &signal, mode)
+            );
+        }
+    }
+
+    #[test]
+    fn test_identity_kernel() {
+        let signal = [4.0, -1.0, 2.5, 0.0];
+        for mode in MODES {
+            assert_eq!(convolve1d(&signal, &[1.0], mode), signal.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_correlate() {
+        let signal = [1.0, 2.0, 3.0];
+        let kernel = [0.0, 1.0, 0.5];
+        assert_eq!(
+            correlate1d(&signal, &kernel, ConvMode::Full),
+            vec![0.5, 2.0, 3.5, 3.0, 0.0]
+        );
+        assert_eq!(
+            correlate1d(&signal, &kernel, ConvMode::Same),
+            vec![2.0, 3.5, 3.0]
+        );
+        assert_eq!(correlate1d(&signal, &kernel, ConvMode::Valid), vec![3.5]);
+    }
+
+    #[test]
+    fn test_correlate_is_convolve_with_reversed_kernel() {
+        let signal: Vec<f64> = (0..11).map(|i| ((i * 7) % 5) as f64 - 2.0).collect();
+        let kernel = [0.25, -1.0, 2.0, 0.5];
+        let reversed: Vec<f64> = kernel.iter().rev().copied().collect();
+        for mode in MODES {
+            assert_eq!(
+                correlate1d(&signal, &kernel, mode),
+                convolve1d(&signal, &reversed, mode)
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_inputs() {
+        for mode in MODES {
+            assert!(convolve1d(&[], &[1.0], mode).is_empty());
+            assert!(convolve1d(&[1.0], &[], mode).is_empty());
+            assert!(correlate1d(&[], &[], mode).is_empty());
+        }
+    }
+}
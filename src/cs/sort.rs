@@ -2,12 +2,14 @@ pub mod bubblesort;
 pub mod bucketsort;
 pub mod countingsort;
 pub mod heapsort;
+pub mod histogram;
 pub mod insertionsort;
 pub mod mergesort;
 pub mod quicksort;
 pub mod radixsort;
 pub mod selectionsort;
 pub mod shellsort;
+pub mod topk;
 
 // Re-export sorting algorithms with descriptive names
 pub use bubblesort::sort as bubble_sort;
@@ -16,9 +18,16 @@ pub use countingsort::sort as counting_sort;
 #[cfg(feature = "simd")]
 pub use heapsort::sort_i32 as heap_sort_i32;
 pub use heapsort::{sort as heap_sort, HeapSortError};
+#[cfg(feature = "parallel")]
+pub use histogram::par_value_histogram;
+pub use histogram::value_histogram;
 pub use insertionsort::sort as insertion_sort;
-pub use mergesort::{sort as merge_sort, MergeSortBuilder};
+pub use mergesort::{
+    external_merge_sort, k_way_merge, sort as merge_sort, sort_and_count_inversions,
+    MergeSortBuilder,
+};
 pub use quicksort::sort as quick_sort;
 pub use radixsort::sort as radix_sort;
 pub use selectionsort::sort as selection_sort;
 pub use shellsort::sort as shell_sort;
+pub use topk::top_k;
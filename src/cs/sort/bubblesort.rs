@@ -123,7 +123,7 @@ mod tests {
 
         impl PartialOrd for Item {
             fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                self.key.partial_cmp(&other.key)
+                Some(self.cmp(other))
             }
         }
 
@@ -164,7 +164,7 @@ mod tests {
     #[test]
     fn test_different_types() {
         // Test with floating point numbers
-        let mut float_arr = vec![3.14, 1.41, 2.71, 0.58];
+        let mut float_arr = vec![3.5, 1.41, 2.71, 0.58];
         let mut expected = float_arr.clone();
         expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
         sort(&mut float_arr);
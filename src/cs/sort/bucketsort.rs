@@ -85,7 +85,6 @@ fn get_bucket_index(value: f64, num_buckets: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::f64::EPSILON;
 
     #[test]
     fn test_empty_slice() {
@@ -150,7 +149,7 @@ mod tests {
             original_index: usize,
         }
 
-        let pairs = vec![
+        let pairs = [
             Pair {
                 key: 0.5,
                 original_index: 0,
@@ -175,14 +174,14 @@ mod tests {
         // Verify that relative order is preserved for equal keys
         for i in 0..pairs.len() - 1 {
             for j in i + 1..pairs.len() {
-                if (pairs[i].key - pairs[j].key).abs() < EPSILON {
+                if (pairs[i].key - pairs[j].key).abs() < f64::EPSILON {
                     let pos_i = values
                         .iter()
-                        .position(|&x| (x - pairs[i].key).abs() < EPSILON)
+                        .position(|&x| (x - pairs[i].key).abs() < f64::EPSILON)
                         .unwrap();
                     let pos_j = values
                         .iter()
-                        .rposition(|&x| (x - pairs[j].key).abs() < EPSILON)
+                        .rposition(|&x| (x - pairs[j].key).abs() < f64::EPSILON)
                         .unwrap();
                     assert!(
                         pos_i < pos_j,
@@ -159,7 +159,7 @@ mod tests {
             original_index: usize,
         }
 
-        let pairs = vec![
+        let pairs = [
             Pair {
                 key: 1,
                 original_index: 0,
@@ -183,7 +183,7 @@ mod tests {
 
         // Create a mapping of sorted positions
         let mut position_map = vec![0; pairs.len()];
-        let mut count = vec![0; 3]; // Count array for values 0-2
+        let mut count = [0; 3]; // Count array for values 0-2
 
         // Count frequencies
         for &value in values.iter() {
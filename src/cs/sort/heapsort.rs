@@ -1,40 +1,40 @@
-/// Heapsort implementation for sorting slices.
-///
-/// # Algorithm Overview
-/// Heapsort is a comparison-based sorting algorithm that uses a binary heap data structure.
-/// The algorithm:
-/// 1. Builds a max-heap from the input array using Floyd's bottom-up construction (O(n))
-/// 2. Repeatedly extracts the maximum element and places it at the end (n * O(log n))
-/// 3. Maintains the heap property after each extraction (O(log n) per operation)
-///
-/// # Performance Characteristics
-/// - Cache behavior: Moderate, with non-sequential access patterns
-/// - Branch prediction: Optimized for common cases
-/// - Memory usage: In-place, O(1) auxiliary space
-///
-/// # Time Complexity
-/// - Build heap: O(n)
-/// - Heapify: O(log n)
-/// - Overall: O(n log n) for all cases
-///
-/// # Space Complexity
-/// - O(1) auxiliary space
-/// - In-place sorting
-///
-/// # Stability
-/// - Not stable: equal elements may be reordered
-///
-/// # Examples
-/// ```
-/// use blocks::cs::sort::heap_sort;
-/// let mut numbers = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
-/// heap_sort(&mut numbers).expect("Sort should succeed");
-/// assert_eq!(numbers, vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9]);
-/// ```
-///
-/// # Errors
-/// Returns `HeapSortError::ArrayTooLarge` if the array is too large to safely process
-/// Returns `HeapSortError::InvalidRootIndex` if an invalid root index is provided to heapify
+//! Heapsort implementation for sorting slices.
+//!
+//! # Algorithm Overview
+//! Heapsort is a comparison-based sorting algorithm that uses a binary heap data structure.
+//! The algorithm:
+//! 1. Builds a max-heap from the input array using Floyd's bottom-up construction (O(n))
+//! 2. Repeatedly extracts the maximum element and places it at the end (n * O(log n))
+//! 3. Maintains the heap property after each extraction (O(log n) per operation)
+//!
+//! # Performance Characteristics
+//! - Cache behavior: Moderate, with non-sequential access patterns
+//! - Branch prediction: Optimized for common cases
+//! - Memory usage: In-place, O(1) auxiliary space
+//!
+//! # Time Complexity
+//! - Build heap: O(n)
+//! - Heapify: O(log n)
+//! - Overall: O(n log n) for all cases
+//!
+//! # Space Complexity
+//! - O(1) auxiliary space
+//! - In-place sorting
+//!
+//! # Stability
+//! - Not stable: equal elements may be reordered
+//!
+//! # Examples
+//! ```
+//! use blocks::cs::sort::heap_sort;
+//! let mut numbers = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+//! heap_sort(&mut numbers).expect("Sort should succeed");
+//! assert_eq!(numbers, vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9]);
+//! ```
+//!
+//! # Errors
+//! Returns `HeapSortError::ArrayTooLarge` if the array is too large to safely process
+//! Returns `HeapSortError::InvalidRootIndex` if an invalid root index is provided to heapify
 
 /// Error type for heap sort operations
 #[derive(Debug)]
@@ -354,7 +354,7 @@ mod tests {
         let sizes = [2, 4, 8, 16, 32, 64, 128, 256, 512, 1024];
 
         for &size in &sizes {
-            let mut arr: Vec<i32> = (0..size).map(|x| x as i32).rev().collect();
+            let mut arr: Vec<i32> = (0..size).rev().collect();
 
             // First build the heap and verify heap property
             build_max_heap(&mut arr).expect("Build heap should succeed");
@@ -394,10 +394,10 @@ mod tests {
         let boundary_sizes = [8, 9, 10, 11, 15, 16, 17, 32, 1023, 1024, 1025];
 
         for &size in &boundary_sizes {
-            let mut arr: Vec<i32> = (0..size).map(|x| x as i32).rev().collect();
+            let mut arr: Vec<i32> = (0..size).rev().collect();
             let mut expected = arr.clone();
             expected.sort();
-            sort(&mut arr).expect(&format!("Sort should succeed for size {}", size));
+            sort(&mut arr).unwrap_or_else(|_| panic!("Sort should succeed for size {}", size));
             assert_eq!(arr, expected, "Failed for size {}", size);
         }
     }
@@ -410,27 +410,21 @@ mod tests {
         assert!(arr.windows(2).all(|w| w[0] <= w[1]));
 
         // Alternating elements
-        let mut arr: Vec<i32> = (0..100).map(|i| (i % 2) as i32).collect();
+        let mut arr: Vec<i32> = (0..100).map(|i| i % 2).collect();
         let mut expected = arr.clone();
         expected.sort();
         sort(&mut arr).expect("Sort should succeed on alternating elements");
         assert_eq!(arr, expected);
 
         // Saw pattern
-        let mut arr: Vec<i32> = (0..50)
-            .map(|x| x as i32)
-            .chain((0..50).rev().map(|x| x as i32))
-            .collect();
+        let mut arr: Vec<i32> = (0..50).chain((0..50).rev()).collect();
         let mut expected = arr.clone();
         expected.sort();
         sort(&mut arr).expect("Sort should succeed on saw pattern");
         assert_eq!(arr, expected);
 
         // Pipeline pattern
-        let mut arr: Vec<i32> = (0..50)
-            .map(|x| x as i32)
-            .chain((0..50).map(|x| x as i32))
-            .collect();
+        let mut arr: Vec<i32> = (0..50).chain(0..50).collect();
         let mut expected = arr.clone();
         expected.sort();
         sort(&mut arr).expect("Sort should succeed on pipeline pattern");
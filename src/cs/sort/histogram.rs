@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Counts how many times each distinct value occurs in a slice.
+///
+/// This is useful for profiling the distribution of sort keys before choosing an
+/// algorithm, e.g. preferring counting sort when there are few distinct keys.
+///
+/// # Time Complexity
+/// - O(n) expected
+///
+/// # Space Complexity
+/// - O(d) where d is the number of distinct values
+///
+/// # Examples
+/// ```
+/// use blocks::cs::sort::histogram::value_histogram;
+/// let counts = value_histogram(&[3, 1, 3, 2, 3]);
+/// assert_eq!(counts[&3], 3);
+/// assert_eq!(counts[&1], 1);
+/// assert_eq!(counts.len(), 3);
+/// ```
+pub fn value_histogram<T: Eq + Hash + Clone>(slice: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for value in slice {
+        *counts.entry(value.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Parallel version of [`value_histogram`].
+///
+/// Each rayon task counts a chunk of the slice into its own map, and the partial
+/// maps are then merged pairwise, so no locking is needed. The result is identical
+/// to the sequential histogram.
+///
+/// # Examples
+/// ```
+/// use blocks::cs::sort::histogram::par_value_histogram;
+/// let data: Vec<u32> = (0..10_000).map(|i| i % 7).collect();
+/// let counts = par_value_histogram(&data);
+/// assert_eq!(counts.values().sum::<usize>(), data.len());
+/// ```
+#[cfg(feature = "parallel")]
+pub fn par_value_histogram<T: Eq + Hash + Clone + Send + Sync>(slice: &[T]) -> HashMap<T, usize> {
+    slice
+        .par_iter()
+        .fold(HashMap::new, |mut counts, value| {
+            *counts.entry(value.clone()).or_insert(0) += 1;
+            counts
+        })
+        .reduce(HashMap::new, |a, b| {
+            // Merge the smaller map into the larger one
+            let (mut merged, partial) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+            for (value, count) in partial {
+                *merged.entry(value).or_insert(0) += count;
+            }
+            merged
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_slice() {
+        let counts = value_histogram::<i32>(&[]);
+        assert!(counts.is_empty());
+    }
+
+    #[test]
+    fn test_counts() {
+        let counts = value_histogram(&["b", "a", "b", "c", "b", "a"]);
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["a"], 2);
+        assert_eq!(counts["b"], 3);
+        assert_eq!(counts["c"], 1);
+    }
+
+    #[test]
+    fn test_counts_sum_to_length() {
+        let data: Vec<u32> = (0..5_000).map(|i| (i * 31) % 97).collect();
+        let counts = value_histogram(&data);
+        assert_eq!(counts.len(), 97);
+        assert_eq!(counts.values().sum::<usize>(), data.len());
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_parallel_matches_sequential() {
+        let data: Vec<u64> = (0..200_000).map(|i| (i * 7919) % 1_000).collect();
+
+        let sequential = value_histogram(&data);
+        let parallel = par_value_histogram(&data);
+
+        assert_eq!(sequential, parallel);
+        assert_eq!(parallel.values().sum::<usize>(), data.len());
+    }
+}
@@ -15,6 +15,8 @@ use rayon;
 ///
 /// All unsafe operations are properly encapsulated and safe when used with types
 /// that implement the required traits (Send + Sync for parallel execution).
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::fmt::Debug;
 
 use crate::error::{Result, SortError};
@@ -166,7 +168,7 @@ impl MergeSortBuilder {
 
     fn sort_sequential<T>(&self, slice: &mut [T], aux: &mut Vec<T>, depth: usize) -> Result<()>
     where
-        T: Ord + Clone + 'static,
+        T: Ord + Clone,
     {
         if depth >= self.max_recursion_depth {
             return Err(SortError::recursion_limit_exceeded(
@@ -269,6 +271,138 @@ where
     MergeSortBuilder::new().sort(slice)
 }
 
+/// Sorts a slice with merge sort and returns the number of inversions it contained.
+///
+/// An inversion is a pair of positions `i < j` with `slice[i] > slice[j]`. Whenever
+/// the merge step takes an element from the right half, that element is smaller than
+/// every element still waiting in the left half, so all of those pairs are counted at
+/// once. Equal elements are not inversions, and the sort remains stable.
+///
+/// # Examples
+/// ```
+/// use blocks::cs::sort::mergesort::sort_and_count_inversions;
+///
+/// let mut arr = vec![2, 4, 1, 3, 5];
+/// assert_eq!(sort_and_count_inversions(&mut arr).unwrap(), 3);
+/// assert_eq!(arr, vec![1, 2, 3, 4, 5]);
+/// ```
+///
+/// # Performance
+/// - Time: O(n log n) in all cases
+/// - Space: O(n) auxiliary space
+///
+/// # Errors
+///
+/// Returns `SortError` if:
+/// - Input slice is too large (> 2^48 elements)
+pub fn sort_and_count_inversions<T: Ord + Clone>(slice: &mut [T]) -> Result<u64> {
+    if slice.len() <= 1 {
+        return Ok(0);
+    }
+
+    MergeSortBuilder::new().validate_array_size(slice.len())?;
+
+    let mut aux = slice.to_vec();
+    Ok(count_inversions_recursive(slice, &mut aux))
+}
+
+/// Sorts a slice the way an external merge sort would, one block at a time.
+///
+/// The slice is split into runs of `block_size` elements, each run is sorted in place
+/// with the sequential merge sort, and the sorted runs are then combined with
+/// [`k_way_merge`]. Only one block of scratch space is
+/// needed for the run sorts, mirroring an external sort whose memory holds one block,
+/// which makes this useful for studying how block size affects cache behaviour.
+/// Ties between runs are broken by run order, so the sort is stable.
+///
+/// # Examples
+/// ```
+/// use blocks::cs::sort::mergesort::external_merge_sort;
+///
+/// let mut arr = vec![9, 4, 7, 1, 8, 2, 6, 3, 5];
+/// external_merge_sort(&mut arr, 4).unwrap();
+/// assert_eq!(arr, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+///
+/// # Performance
+/// - Time: O(n log b) to sort runs of size b, plus O(n log(n / b)) to merge them
+/// - Space: O(n) for the merge output
+///
+/// # Errors
+///
+/// Returns `SortError` if:
+/// - `block_size` is zero
+/// - Input slice is too large (> 2^48 elements)
+pub fn external_merge_sort<T: Ord + Clone>(slice: &mut [T], block_size: usize) -> Result<()> {
+    if block_size == 0 {
+        return Err(SortError::invalid_input(
+            "block size must be greater than zero",
+        ));
+    }
+    if slice.len() <= 1 {
+        return Ok(());
+    }
+
+    let builder = MergeSortBuilder::new();
+    builder.validate_array_size(slice.len())?;
+
+    let mut aux = slice[..block_size.min(slice.len())].to_vec();
+    for block in slice.chunks_mut(block_size) {
+        builder.sort_sequential(block, &mut aux, 0)?;
+    }
+    if block_size >= slice.len() {
+        return Ok(());
+    }
+
+    let runs = slice.to_vec();
+    let blocks: Vec<&[T]> = runs.chunks(block_size).collect();
+    slice.clone_from_slice(&k_way_merge(&blocks));
+    Ok(())
+}
+
+/// Merges several sorted sequences into one sorted vector.
+///
+/// A min-heap holds the current head of every non-empty sequence, keyed on the head
+/// and then the sequence's index, so each step emits the smallest remaining element
+/// in O(log k). Equal elements keep the order of the sequences they came from, and
+/// their order within a sequence, so the merge is stable.
+///
+/// # Examples
+/// ```
+/// use blocks::cs::sort::mergesort::k_way_merge;
+///
+/// let merged = k_way_merge(&[&[1, 4, 7][..], &[2, 5], &[], &[0, 3, 6, 8]]);
+/// assert_eq!(merged, vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+/// ```
+///
+/// # Performance
+/// - Time: O(n log k) for n elements across k sequences
+/// - Space: O(n) for the output plus O(k) for the heap
+///
+/// The input sequences must each be sorted; otherwise the output order is unspecified.
+pub fn k_way_merge<T: Ord + Clone>(sequences: &[&[T]]) -> Vec<T> {
+    let total = sequences.iter().map(|sequence| sequence.len()).sum();
+    let mut merged = Vec::with_capacity(total);
+    let mut next = vec![0; sequences.len()];
+    let mut heap: BinaryHeap<Reverse<(T, usize)>> = BinaryHeap::with_capacity(sequences.len());
+
+    for (index, sequence) in sequences.iter().enumerate() {
+        if let Some(head) = sequence.first() {
+            heap.push(Reverse((head.clone(), index)));
+            next[index] = 1;
+        }
+    }
+
+    while let Some(Reverse((value, index))) = heap.pop() {
+        merged.push(value);
+        if let Some(head) = sequences[index].get(next[index]) {
+            heap.push(Reverse((head.clone(), index)));
+            next[index] += 1;
+        }
+    }
+    merged
+}
+
 // Internal helper functions
 
 fn insertion_sort<T: Ord>(slice: &mut [T]) {
@@ -281,6 +415,67 @@ fn insertion_sort<T: Ord>(slice: &mut [T]) {
     }
 }
 
+fn count_inversions_recursive<T: Ord + Clone>(slice: &mut [T], aux: &mut [T]) -> u64 {
+    if slice.len() <= MergeSortBuilder::default().insertion_threshold {
+        return insertion_sort_counting(slice);
+    }
+
+    let mid = slice.len() / 2;
+    let left = count_inversions_recursive(&mut slice[..mid], aux);
+    let right = count_inversions_recursive(&mut slice[mid..], aux);
+    left + right + merge_counting(slice, mid, aux)
+}
+
+/// Insertion sort that returns the number of adjacent swaps, which equals the
+/// number of inversions.
+fn insertion_sort_counting<T: Ord>(slice: &mut [T]) -> u64 {
+    let mut swaps = 0;
+    for i in 1..slice.len() {
+        let mut j = i;
+        while j > 0 && slice[j - 1] > slice[j] {
+            slice.swap(j - 1, j);
+            j -= 1;
+            swaps += 1;
+        }
+    }
+    swaps
+}
+
+/// Like `merge`, but returns the number of inversions between the two halves.
+fn merge_counting<T>(slice: &mut [T], mid: usize, aux: &mut [T]) -> u64
+where
+    T: Ord + Clone,
+{
+    aux[..slice.len()].clone_from_slice(slice);
+
+    let (left, right) = aux[..slice.len()].split_at(mid);
+    let mut inversions = 0;
+    let mut i = 0;
+    let mut j = 0;
+    let mut k = 0;
+
+    while i < left.len() && j < right.len() {
+        if left[i] <= right[j] {
+            slice[k] = left[i].clone();
+            i += 1;
+        } else {
+            // right[j] is smaller than every remaining element of the left half
+            slice[k] = right[j].clone();
+            inversions += (left.len() - i) as u64;
+            j += 1;
+        }
+        k += 1;
+    }
+
+    if i < left.len() {
+        slice[k..].clone_from_slice(&left[i..]);
+    }
+    if j < right.len() {
+        slice[k..].clone_from_slice(&right[j..]);
+    }
+    inversions
+}
+
 fn merge<T>(slice: &mut [T], mid: usize, aux: &mut [T])
 where
     T: Ord + Clone,
@@ -314,6 +509,38 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cmp::Ordering;
+
+    /// Ordered by its key alone, so the tag shows where equal keys came from.
+    #[derive(Debug, Clone)]
+    struct Keyed<T>(usize, T);
+
+    impl<T> PartialEq for Keyed<T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl<T> Eq for Keyed<T> {}
+
+    impl<T> PartialOrd for Keyed<T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<T> Ord for Keyed<T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    /// Asserts that keys ascend and equal keys keep their original positions.
+    fn assert_stably_sorted(items: &[Keyed<usize>]) {
+        for w in items.windows(2) {
+            assert!(w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1));
+        }
+    }
 
     #[test]
     fn test_empty_slice() {
@@ -418,7 +645,7 @@ mod tests {
 
         impl PartialOrd for Item {
             fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                self.key.partial_cmp(&other.key)
+                Some(self.cmp(other))
             }
         }
 
@@ -486,4 +713,134 @@ mod tests {
             _ => panic!("Expected InputTooLarge error"),
         }
     }
+
+    fn brute_force_inversions<T: Ord>(slice: &[T]) -> u64 {
+        let mut count = 0;
+        for i in 0..slice.len() {
+            for j in i + 1..slice.len() {
+                if slice[i] > slice[j] {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_count_inversions_sorted() {
+        let mut arr: Vec<i32> = (0..100).collect();
+        assert_eq!(sort_and_count_inversions(&mut arr).unwrap(), 0);
+
+        let mut empty: Vec<i32> = vec![];
+        assert_eq!(sort_and_count_inversions(&mut empty).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_count_inversions_reversed() {
+        for n in [2u64, 5, 16, 17, 100, 1000] {
+            let mut arr: Vec<u64> = (0..n).rev().collect();
+            assert_eq!(
+                sort_and_count_inversions(&mut arr).unwrap(),
+                n * (n - 1) / 2
+            );
+            assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+
+    #[test]
+    fn test_count_inversions_matches_brute_force() {
+        let mut small = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        let expected = brute_force_inversions(&small);
+        assert_eq!(expected, 8);
+        assert_eq!(sort_and_count_inversions(&mut small).unwrap(), expected);
+        assert_eq!(small, vec![1, 1, 2, 3, 4, 5, 6, 9]);
+
+        // Large enough to exercise the merge step, with many duplicates
+        let original: Vec<i32> = (0..500).map(|i| (i * 7919) % 61).collect();
+        let mut arr = original.clone();
+        assert_eq!(
+            sort_and_count_inversions(&mut arr).unwrap(),
+            brute_force_inversions(&original)
+        );
+        let mut expected_sorted = original;
+        expected_sorted.sort();
+        assert_eq!(arr, expected_sorted);
+    }
+
+    #[test]
+    fn test_count_inversions_is_stable() {
+        let mut arr: Vec<Keyed<usize>> = (0..40).map(|i| Keyed(i * 7 % 3, i)).collect();
+        sort_and_count_inversions(&mut arr).unwrap();
+        assert_stably_sorted(&arr);
+    }
+
+    #[test]
+    fn test_external_merge_sort_block_sizes() {
+        let original: Vec<i32> = (0..257).map(|i| (i * 7919) % 101 - 50).collect();
+        let mut expected = original.clone();
+        expected.sort();
+
+        for block_size in [1, 2, 3, 16, 17, 64, 256, 257, 1000] {
+            let mut arr = original.clone();
+            external_merge_sort(&mut arr, block_size).unwrap();
+            assert_eq!(arr, expected, "block size {}", block_size);
+        }
+    }
+
+    #[test]
+    fn test_external_merge_sort_edge_cases() {
+        let mut empty: Vec<i32> = vec![];
+        external_merge_sort(&mut empty, 4).unwrap();
+        assert!(empty.is_empty());
+
+        let mut single = vec![7];
+        external_merge_sort(&mut single, 1).unwrap();
+        assert_eq!(single, vec![7]);
+
+        let mut arr = vec![3, 1, 2];
+        assert!(matches!(
+            external_merge_sort(&mut arr, 0),
+            Err(SortError::InvalidInput(_))
+        ));
+        assert_eq!(arr, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn test_external_merge_sort_is_stable() {
+        let mut items: Vec<Keyed<usize>> = (0..100).map(|i| Keyed(i * 7 % 5, i)).collect();
+        external_merge_sort(&mut items, 7).unwrap();
+        assert_stably_sorted(&items);
+    }
+
+    #[test]
+    fn test_k_way_merge_matches_sort() {
+        let a = [1, 3, 3, 9, 12];
+        let b = [2];
+        let c: [i32; 0] = [];
+        let d = [-4, 0, 3, 8, 8, 10, 11, 20];
+        let e = [5, 6];
+        let sequences: [&[i32]; 5] = [&a, &b, &c, &d, &e];
+
+        let mut expected = sequences.concat();
+        expected.sort();
+        assert_eq!(k_way_merge(&sequences), expected);
+    }
+
+    #[test]
+    fn test_k_way_merge_empty_inputs() {
+        assert!(k_way_merge::<i32>(&[]).is_empty());
+        assert!(k_way_merge::<i32>(&[&[], &[]]).is_empty());
+        assert_eq!(k_way_merge(&[&[], &[1, 2][..], &[]]), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_k_way_merge_is_stable() {
+        let first = [Keyed(1, "a0"), Keyed(2, "a1"), Keyed(2, "a2")];
+        let second = [Keyed(1, "b0"), Keyed(2, "b1")];
+        let third = [Keyed(2, "c0"), Keyed(3, "c1")];
+        let merged = k_way_merge(&[&first[..], &second, &third]);
+
+        let tags: Vec<&str> = merged.iter().map(|t| t.1).collect();
+        assert_eq!(tags, vec!["a0", "b0", "a1", "a2", "b1", "c0", "c1"]);
+    }
 }
@@ -161,7 +161,7 @@ mod tests {
             original_index: usize,
         }
 
-        let pairs = vec![
+        let pairs = [
             Pair {
                 key: 501,
                 original_index: 0,
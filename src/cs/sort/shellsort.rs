@@ -127,7 +127,7 @@ mod tests {
     #[test]
     fn test_different_types() {
         // Test with floating point numbers
-        let mut float_arr = vec![3.14, 1.41, 2.71, 0.58];
+        let mut float_arr = vec![3.5, 1.41, 2.71, 0.58];
         let mut expected = float_arr.clone();
         expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
         sort(&mut float_arr);
@@ -0,0 +1,110 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Selects the `k` largest elements of an iterator in a single pass.
+///
+/// # Algorithm Overview
+/// A min-heap of size at most `k` holds the largest elements seen so far:
+/// 1. Each incoming element is pushed while the heap has fewer than `k` elements
+/// 2. Once full, an element larger than the heap's minimum replaces that minimum
+/// 3. After the input is exhausted the heap contents are returned largest first
+///
+/// # Time Complexity
+/// - O(n log k) where n is the number of elements in the iterator
+///
+/// # Space Complexity
+/// - O(k) auxiliary space, independent of n
+///
+/// # Stability
+/// - Not stable: the relative order of equal elements is not preserved
+///
+/// # Examples
+/// ```
+/// use blocks::cs::sort::topk::top_k;
+/// let numbers = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// assert_eq!(top_k(numbers.into_iter(), 3), vec![9, 6, 5]);
+///
+/// // Asking for more elements than available returns all of them, sorted descending
+/// assert_eq!(top_k([2, 7, 1].into_iter(), 10), vec![7, 2, 1]);
+/// ```
+pub fn top_k<T: Ord, I: Iterator<Item = T>>(iter: I, k: usize) -> Vec<T> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap = BinaryHeap::with_capacity(k);
+    for item in iter {
+        if heap.len() < k {
+            heap.push(Reverse(item));
+        } else if let Some(mut min) = heap.peek_mut() {
+            if item > min.0 {
+                // Replacing through PeekMut re-sifts the heap on drop
+                *min = Reverse(item);
+            }
+        }
+    }
+
+    // Ascending order of Reverse<T> is descending order of T
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference<T: Ord + Clone>(data: &[T], k: usize) -> Vec<T> {
+        let mut sorted = data.to_vec();
+        sorted.sort_by(|a, b| b.cmp(a));
+        sorted.truncate(k);
+        sorted
+    }
+
+    #[test]
+    fn test_empty_iterator() {
+        let result = top_k(Vec::<i32>::new().into_iter(), 3);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_k_zero() {
+        assert!(top_k(vec![1, 2, 3].into_iter(), 0).is_empty());
+    }
+
+    #[test]
+    fn test_basic() {
+        let data = [5, 1, 9, 3, 7, 2, 8];
+        assert_eq!(top_k(data.iter().copied(), 3), vec![9, 8, 7]);
+    }
+
+    #[test]
+    fn test_k_larger_than_input() {
+        let data = [4, 2, 6, 1];
+        assert_eq!(top_k(data.iter().copied(), 10), vec![6, 4, 2, 1]);
+        assert_eq!(top_k(data.iter().copied(), 4), vec![6, 4, 2, 1]);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let data = [3, 3, 1, 3, 2, 2];
+        assert_eq!(top_k(data.iter().copied(), 4), vec![3, 3, 3, 2]);
+    }
+
+    #[test]
+    fn test_strings() {
+        let data = vec!["pear", "apple", "zucchini", "fig"];
+        assert_eq!(top_k(data.into_iter(), 2), vec!["zucchini", "pear"]);
+    }
+
+    #[test]
+    fn test_matches_full_sort() {
+        // Scrambled values with many duplicates
+        let data: Vec<i32> = (0..1_000).map(|i| (i * 7919 % 601) - 300).collect();
+
+        for k in [1, 5, 10, 100, 999, 1_000, 1_500] {
+            assert_eq!(top_k(data.iter().copied(), k), reference(&data, k));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::cmp::Ordering;
+
+pub mod hypothesis;
+pub mod quantile;
+pub mod sampling;
+pub mod sliding_window;
+pub mod tdigest;
+pub mod weighted;
+
+// Re-export statistics functions
+pub use hypothesis::{mann_whitney_u, welch_t_test};
+pub use quantile::{percentile, quartiles, InterpolationMethod};
+pub use sampling::weighted_reservoir_sample;
+pub use sliding_window::{sliding_window_max, sliding_window_median};
+pub use tdigest::TDigest;
+pub use weighted::{weighted_mean, weighted_quantile};
+
+/// An `f64` ordered by `total_cmp`, so it can be used in ordered collections
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TotalF64(pub(crate) f64);
+
+impl PartialEq for TotalF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalF64 {}
+
+impl PartialOrd for TotalF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
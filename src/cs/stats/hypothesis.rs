@@ -0,0 +1,411 @@
+use crate::cs::error::{Error, Result};
+
+/// Performs Welch's two-sample t-test, which does not assume equal variances.
+///
+/// The degrees of freedom come from the Welch-Satterthwaite equation, and the
+/// two-sided p-value is computed from the Student's t distribution through the
+/// regularized incomplete beta function.
+///
+/// # Arguments
+/// * `a` - The first sample
+/// * `b` - The second sample
+///
+/// # Returns
+/// * `Result<(f64, f64)>` - The t-statistic (positive when `a` has the larger mean)
+///   and the two-sided p-value
+///
+/// # Errors
+/// * `Error::InvalidInput` if either sample has fewer than two values or contains
+///   NaN or an infinite value, or if both samples have zero variance
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::hypothesis;
+/// #
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let b = [2.0, 4.0, 6.0, 8.0, 10.0];
+/// let (t, p) = hypothesis::welch_t_test(&a, &b).unwrap();
+/// assert!((t + 1.897366596).abs() < 1e-9);
+/// assert!(p > 0.05);
+/// ```
+///
+/// # Performance
+/// * Time: O(n + m)
+/// * Space: O(1)
+pub fn welch_t_test(a: &[f64], b: &[f64]) -> Result<(f64, f64)> {
+    for sample in [a, b] {
+        if sample.len() < 2 {
+            return Err(Error::invalid_input(
+                "each sample must contain at least two values",
+            ));
+        }
+        validate_sample(sample)?;
+    }
+
+    let (mean_a, var_a) = mean_and_variance(a);
+    let (mean_b, var_b) = mean_and_variance(b);
+    let se_a = var_a / a.len() as f64;
+    let se_b = var_b / b.len() as f64;
+    let se = se_a + se_b;
+    if se == 0.0 {
+        return Err(Error::invalid_input("both samples have zero variance"));
+    }
+
+    let t = (mean_a - mean_b) / se.sqrt();
+    let df = se * se / (se_a * se_a / (a.len() - 1) as f64 + se_b * se_b / (b.len() - 1) as f64);
+
+    Ok((t, student_t_two_sided_p(t, df)))
+}
+
+/// Performs the Mann-Whitney U test, a nonparametric test of whether values from
+/// one sample tend to be larger than values from the other.
+///
+/// Ranks are averaged over ties, and the p-value uses the normal approximation
+/// with tie and continuity corrections, so it is most accurate once both samples
+/// have more than a handful of values.
+///
+/// # Arguments
+/// * `a` - The first sample
+/// * `b` - The second sample
+///
+/// # Returns
+/// * `Result<(f64, f64)>` - The U statistic of `a` (the number of pairs in which the
+///   `a` value is larger, counting ties as one half) and the two-sided p-value
+///
+/// # Errors
+/// * `Error::InvalidInput` if either sample is empty or contains NaN or an infinite
+///   value
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::hypothesis;
+/// #
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let b = [6.0, 7.0, 8.0, 9.0, 10.0];
+/// let (u, p) = hypothesis::mann_whitney_u(&a, &b).unwrap();
+/// assert_eq!(u, 0.0);
+/// assert!(p < 0.05);
+/// ```
+///
+/// # Performance
+/// * Time: O((n + m) log(n + m))
+/// * Space: O(n + m)
+pub fn mann_whitney_u(a: &[f64], b: &[f64]) -> Result<(f64, f64)> {
+    for sample in [a, b] {
+        if sample.is_empty() {
+            return Err(Error::invalid_input("samples must not be empty"));
+        }
+        validate_sample(sample)?;
+    }
+
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let n = n_a + n_b;
+
+    // Pool the samples, remembering which one each value came from
+    let mut pooled: Vec<(f64, bool)> = a
+        .iter()
+        .map(|&x| (x, true))
+        .chain(b.iter().map(|&x| (x, false)))
+        .collect();
+    pooled.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut start = 0;
+    while start < pooled.len() {
+        let mut end = start + 1;
+        while end < pooled.len() && pooled[end].0 == pooled[start].0 {
+            end += 1;
+        }
+        // Ranks are 1-based; tied values share the mean of their ranks
+        let average_rank = (start + end + 1) as f64 / 2.0;
+        let from_a = pooled[start..end].iter().filter(|(_, in_a)| *in_a).count();
+        rank_sum_a += average_rank * from_a as f64;
+
+        let t = (end - start) as f64;
+        tie_term += t * t * t - t;
+        start = end;
+    }
+
+    let u = rank_sum_a - n_a * (n_a + 1.0) / 2.0;
+    let mean_u = n_a * n_b / 2.0;
+    let var_u = n_a * n_b / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)));
+    if var_u <= 0.0 {
+        // Every value is tied, so the samples are indistinguishable
+        return Ok((u, 1.0));
+    }
+
+    let z = ((u - mean_u).abs() - 0.5).max(0.0) / var_u.sqrt();
+    Ok((u, erfc(z / std::f64::consts::SQRT_2).min(1.0)))
+}
+
+fn validate_sample(sample: &[f64]) -> Result<()> {
+    if sample.iter().any(|x| !x.is_finite()) {
+        return Err(Error::invalid_input(
+            "samples must not contain NaN or infinite values",
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the mean and the unbiased sample variance.
+fn mean_and_variance(sample: &[f64]) -> (f64, f64) {
+    let n = sample.len() as f64;
+    let mean = sample.iter().sum::<f64>() / n;
+    let variance = sample.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+/// Two-sided tail probability P(|T| >= |t|) for Student's t with `df` degrees of freedom.
+fn student_t_two_sided_p(t: f64, df: f64) -> f64 {
+    if t.is_infinite() {
+        return 0.0;
+    }
+    regularized_incomplete_beta(df / 2.0, 0.5, df / (df + t * t))
+}
+
+/// Regularized incomplete beta function I_x(a, b), evaluated with a continued fraction.
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    // The continued fraction converges quickly only on this side of the mean
+    if x < (a + 1.0) / (a + b + 2.0) {
+        ln_front.exp() * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - ln_front.exp() * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Continued fraction for the incomplete beta function, using the modified Lentz method.
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 300;
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+
+    let guard = |v: f64| if v.abs() < TINY { TINY } else { v };
+
+    let mut c = 1.0;
+    let mut d = 1.0 / guard(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+
+        // Even step
+        let numerator = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / guard(1.0 + numerator * d);
+        c = guard(1.0 + numerator / c);
+        h *= d * c;
+
+        // Odd step
+        let numerator = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / guard(1.0 + numerator * d);
+        c = guard(1.0 + numerator / c);
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Natural logarithm of the gamma function for positive arguments (Lanczos approximation).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    const G: f64 = 7.0;
+
+    let x = x - 1.0;
+    let t = x + G + 0.5;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |acc, (i, &c)| {
+            acc + c / (x + i as f64 + 1.0)
+        });
+
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// Complementary error function, accurate to about 1.2e-7.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let polynomial = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let result = t * polynomial.exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        // Γ(n) = (n - 1)!
+        assert_close(ln_gamma(1.0), 0.0, 1e-12);
+        assert_close(ln_gamma(5.0), 24f64.ln(), 1e-12);
+        // Γ(1/2) = √π
+        assert_close(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln(), 1e-12);
+    }
+
+    #[test]
+    fn test_student_t_p_values() {
+        // With one degree of freedom t is Cauchy-distributed
+        for t in [0.5, 1.0, 3.0, 12.0] {
+            let expected = 1.0 - 2.0 / std::f64::consts::PI * f64::atan(t);
+            assert_close(student_t_two_sided_p(t, 1.0), expected, 1e-10);
+        }
+        // Critical values from standard t tables at the 5% level
+        assert_close(student_t_two_sided_p(2.228_138_85, 10.0), 0.05, 1e-7);
+        assert_close(student_t_two_sided_p(-2.042_272_46, 30.0), 0.05, 1e-7);
+        assert_close(student_t_two_sided_p(0.0, 7.0), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_welch_statistic_by_hand() {
+        // Means 3 and 6, variances 2.5 and 10: t = -3 / sqrt(2.5 / 5 + 10 / 5)
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let b = [2.0, 4.0, 6.0, 8.0, 10.0];
+        let (t, p) = welch_t_test(&a, &b).unwrap();
+        assert_close(t, -3.0 / 2.5f64.sqrt(), 1e-12);
+        assert!(p > 0.05 && p < 0.2, "p = {}", p);
+
+        // Swapping the samples flips the sign only
+        let (t_swapped, p_swapped) = welch_t_test(&b, &a).unwrap();
+        assert_close(t_swapped, -t, 1e-12);
+        assert_close(p_swapped, p, 1e-12);
+    }
+
+    #[test]
+    fn test_welch_different_distributions() {
+        let a: Vec<f64> = (0..30).map(|i| 10.0 + (i % 7) as f64 * 0.3).collect();
+        let b: Vec<f64> = (0..25).map(|i| 14.0 + (i % 5) as f64 * 0.4).collect();
+        let (t, p) = welch_t_test(&a, &b).unwrap();
+        assert!(t < 0.0);
+        assert!(p < 1e-6, "p = {}", p);
+    }
+
+    #[test]
+    fn test_welch_identical_samples() {
+        let a = [1.0, 4.0, 2.0, 8.0];
+        let (t, p) = welch_t_test(&a, &a).unwrap();
+        assert_eq!(t, 0.0);
+        assert_close(p, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_welch_invalid_input() {
+        assert!(matches!(
+            welch_t_test(&[1.0], &[1.0, 2.0]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            welch_t_test(&[3.0, 3.0], &[5.0, 5.0, 5.0]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            welch_t_test(&[1.0, f64::NAN], &[1.0, 2.0]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            welch_t_test(&[1.0, 2.0, f64::INFINITY], &[1.0, 2.0]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            welch_t_test(&[1.0, 2.0], &[f64::NEG_INFINITY, 2.0]),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_mann_whitney_separated_samples() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let b = [6.0, 7.0, 8.0, 9.0, 10.0];
+        // z = (12.5 - 0.5) / sqrt(25 * 11 / 12)
+        let (u, p) = mann_whitney_u(&a, &b).unwrap();
+        assert_eq!(u, 0.0);
+        assert_close(p, 0.012_185_8, 1e-6);
+
+        let (u, p_swapped) = mann_whitney_u(&b, &a).unwrap();
+        assert_eq!(u, 25.0);
+        assert_close(p_swapped, p, 1e-12);
+    }
+
+    #[test]
+    fn test_mann_whitney_ties() {
+        // Pairs with the a value larger: 2 > 1, 3 > 1 and 3 > 2, plus 0.5 for the tie at 2
+        let a = [2.0, 3.0];
+        let b = [1.0, 2.0, 4.0];
+        let (u, _) = mann_whitney_u(&a, &b).unwrap();
+        assert_eq!(u, 3.5);
+
+        let (u, p) = mann_whitney_u(&[5.0, 5.0], &[5.0, 5.0, 5.0]).unwrap();
+        assert_eq!(u, 3.0);
+        assert_eq!(p, 1.0);
+    }
+
+    #[test]
+    fn test_mann_whitney_different_distributions() {
+        let a: Vec<f64> = (0..40).map(|i| (i % 10) as f64).collect();
+        let b: Vec<f64> = (0..40).map(|i| 6.0 + (i % 10) as f64).collect();
+        let (_, p) = mann_whitney_u(&a, &b).unwrap();
+        assert!(p < 1e-6, "p = {}", p);
+
+        let (_, p) = mann_whitney_u(&a, &a).unwrap();
+        assert!(p > 0.9, "p = {}", p);
+    }
+
+    #[test]
+    fn test_mann_whitney_invalid_input() {
+        assert!(matches!(
+            mann_whitney_u(&[], &[1.0]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            mann_whitney_u(&[1.0], &[f64::NAN]),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            mann_whitney_u(&[f64::INFINITY, 1.0], &[2.0]),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+use crate::cs::error::{Error, Result};
+
+/// How to pick a percentile that falls between two order statistics.
+///
+/// For `n` sorted values the q-th percentile lies at the fractional index
+/// `h = (n - 1) * q / 100`, between the values at `floor(h)` and `ceil(h)`.
+/// The variants match NumPy's `method` argument of the same names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterpolationMethod {
+    /// Interpolates linearly between the two neighbouring values
+    #[default]
+    Linear,
+    /// Takes the value at `floor(h)`
+    Lower,
+    /// Takes the value at `ceil(h)`
+    Higher,
+    /// Takes the value at the index nearest to `h`, rounding halves to even
+    Nearest,
+}
+
+/// Computes the q-th percentile of a data set.
+///
+/// The required order statistics are found with selection on a copy of the data
+/// rather than a full sort.
+///
+/// # Arguments
+/// * `data` - The values, in any order
+/// * `q` - The percentile to compute, between 0 and 100 inclusive
+/// * `method` - How to resolve percentiles between two values
+///
+/// # Returns
+/// * `Result<f64>` - The percentile. `q = 0` gives the minimum and `q = 100` the maximum.
+///
+/// # Errors
+/// * `Error::InvalidInput` if `data` is empty or contains NaN, or if `q` is outside [0, 100]
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::quantile::{self, InterpolationMethod};
+/// #
+/// let data = [10.0, 7.0, 4.0, 3.0, 2.0, 1.0];
+/// assert_eq!(quantile::percentile(&data, 50.0, InterpolationMethod::Linear).unwrap(), 3.5);
+/// assert_eq!(quantile::percentile(&data, 50.0, InterpolationMethod::Lower).unwrap(), 3.0);
+/// assert_eq!(quantile::percentile(&data, 50.0, InterpolationMethod::Higher).unwrap(), 4.0);
+/// ```
+///
+/// # Performance
+/// * Time: O(n) on average
+/// * Space: O(n) for the working copy
+pub fn percentile(data: &[f64], q: f64, method: InterpolationMethod) -> Result<f64> {
+    validate_data(data)?;
+    validate_percentile(q)?;
+
+    let mut values = data.to_vec();
+    Ok(select_percentile(&mut values, q, method))
+}
+
+/// Computes the first quartile, the median and the third quartile of a data set,
+/// using linear interpolation.
+///
+/// # Arguments
+/// * `data` - The values, in any order
+///
+/// # Returns
+/// * `Result<(f64, f64, f64)>` - The 25th, 50th and 75th percentiles
+///
+/// # Errors
+/// * `Error::InvalidInput` if `data` is empty or contains NaN
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::quantile;
+/// #
+/// let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+/// assert_eq!(quantile::quartiles(&data).unwrap(), (3.25, 5.5, 7.75));
+/// ```
+///
+/// # Performance
+/// * Time: O(n) on average
+/// * Space: O(n) for the working copy
+pub fn quartiles(data: &[f64]) -> Result<(f64, f64, f64)> {
+    validate_data(data)?;
+
+    // Each selection leaves the slice partitioned, which the next one can reuse
+    let mut values = data.to_vec();
+    let linear = InterpolationMethod::Linear;
+    Ok((
+        select_percentile(&mut values, 25.0, linear),
+        select_percentile(&mut values, 50.0, linear),
+        select_percentile(&mut values, 75.0, linear),
+    ))
+}
+
+fn validate_data(data: &[f64]) -> Result<()> {
+    if data.is_empty() {
+        return Err(Error::invalid_input("data must not be empty"));
+    }
+    if data.iter().any(|x| x.is_nan()) {
+        return Err(Error::invalid_input("data must not contain NaN"));
+    }
+    Ok(())
+}
+
+fn validate_percentile(q: f64) -> Result<()> {
+    if !(0.0..=100.0).contains(&q) {
+        return Err(Error::invalid_input(format!(
+            "percentile must be between 0 and 100, got {}",
+            q
+        )));
+    }
+    Ok(())
+}
+
+/// Computes a percentile of non-empty, NaN-free values, reordering them in place.
+fn select_percentile(values: &mut [f64], q: f64, method: InterpolationMethod) -> f64 {
+    let h = (values.len() - 1) as f64 * q / 100.0;
+    let lower = h.floor() as usize;
+
+    match method {
+        InterpolationMethod::Lower => select(values, lower),
+        InterpolationMethod::Higher => select(values, h.ceil() as usize),
+        InterpolationMethod::Nearest => select(values, h.round_ties_even() as usize),
+        InterpolationMethod::Linear => {
+            let low = select(values, lower);
+            let fraction = h - lower as f64;
+            if fraction == 0.0 {
+                return low;
+            }
+            // After selection the next order statistic is the minimum of the right part
+            let high = values[lower + 1..]
+                .iter()
+                .copied()
+                .min_by(f64::total_cmp)
+                .unwrap_or(low);
+            low + (high - low) * fraction
+        }
+    }
+}
+
+/// Returns the k-th smallest value, partitioning the slice around it.
+fn select(values: &mut [f64], k: usize) -> f64 {
+    *values.select_nth_unstable_by(k, f64::total_cmp).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_METHODS: [InterpolationMethod; 4] = [
+        InterpolationMethod::Linear,
+        InterpolationMethod::Lower,
+        InterpolationMethod::Higher,
+        InterpolationMethod::Nearest,
+    ];
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_empty_data() {
+        assert!(matches!(
+            percentile(&[], 50.0, InterpolationMethod::Linear),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(quartiles(&[]), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_nan_data() {
+        assert!(matches!(
+            percentile(&[1.0, f64::NAN], 50.0, InterpolationMethod::Linear),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_percentile_out_of_range() {
+        let data = [1.0, 2.0, 3.0];
+        for q in [-0.1, 100.1, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                percentile(&data, q, InterpolationMethod::Linear),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_matches_numpy_linear() {
+        // np.percentile([10, 7, 4, 3, 2, 1], q)
+        let data = [10.0, 7.0, 4.0, 3.0, 2.0, 1.0];
+        for (q, expected) in [
+            (10.0, 1.5),
+            (25.0, 2.25),
+            (50.0, 3.5),
+            (75.0, 6.25),
+            (90.0, 8.5),
+        ] {
+            assert_close(
+                percentile(&data, q, InterpolationMethod::Linear).unwrap(),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn test_discrete_methods() {
+        // Sorted: [1, 2, 3, 4, 7, 10]; q=75 lies at index 3.75, q=50 at 2.5
+        let data = [10.0, 7.0, 4.0, 3.0, 2.0, 1.0];
+        assert_eq!(
+            percentile(&data, 75.0, InterpolationMethod::Lower).unwrap(),
+            4.0
+        );
+        assert_eq!(
+            percentile(&data, 75.0, InterpolationMethod::Higher).unwrap(),
+            7.0
+        );
+        assert_eq!(
+            percentile(&data, 75.0, InterpolationMethod::Nearest).unwrap(),
+            7.0
+        );
+        // Halfway indices round to even, as in NumPy
+        assert_eq!(
+            percentile(&data, 50.0, InterpolationMethod::Nearest).unwrap(),
+            3.0
+        );
+    }
+
+    #[test]
+    fn test_extremes_are_min_and_max() {
+        let data = [3.5, -2.0, 8.25, 0.0, 4.0];
+        for method in ALL_METHODS {
+            assert_eq!(percentile(&data, 0.0, method).unwrap(), -2.0);
+            assert_eq!(percentile(&data, 100.0, method).unwrap(), 8.25);
+        }
+    }
+
+    #[test]
+    fn test_single_value() {
+        for method in ALL_METHODS {
+            assert_eq!(percentile(&[42.0], 37.0, method).unwrap(), 42.0);
+        }
+        assert_eq!(quartiles(&[42.0]).unwrap(), (42.0, 42.0, 42.0));
+    }
+
+    #[test]
+    fn test_quartiles() {
+        let data = [9.0, 1.0, 8.0, 2.0, 7.0, 3.0, 6.0, 4.0, 5.0, 10.0];
+        assert_eq!(quartiles(&data).unwrap(), (3.25, 5.5, 7.75));
+    }
+
+    #[test]
+    fn test_matches_sorted_reference() {
+        let data: Vec<f64> = (0..101).map(|i| ((i * 7919) % 211) as f64 / 4.0).collect();
+        let mut sorted = data.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        for q in [0.0, 1.0, 12.5, 33.3, 50.0, 66.6, 99.0, 100.0] {
+            let h = (sorted.len() - 1) as f64 * q / 100.0;
+            let (lo, hi) = (h.floor() as usize, h.ceil() as usize);
+            let expected = sorted[lo] + (sorted[hi] - sorted[lo]) * (h - lo as f64);
+            assert_close(
+                percentile(&data, q, InterpolationMethod::Linear).unwrap(),
+                expected,
+            );
+            assert_eq!(
+                percentile(&data, q, InterpolationMethod::Lower).unwrap(),
+                sorted[lo]
+            );
+            assert_eq!(
+                percentile(&data, q, InterpolationMethod::Higher).unwrap(),
+                sorted[hi]
+            );
+        }
+    }
+}
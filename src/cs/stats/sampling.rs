@@ -0,0 +1,200 @@
+use super::TotalF64;
+use crate::cs::util::rng::splitmix64;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Samples `k` items without replacement, with probability proportional to their
+/// weights, in a single pass (the A-Res algorithm of Efraimidis and Spirakis).
+///
+/// Each item draws `u` uniformly from (0, 1) and is given the key `u^(1/weight)`;
+/// the `k` items with the largest keys form the sample. Keys are compared as
+/// `ln(u) / weight`, which preserves their order without underflowing for large
+/// weights. A min-heap of size `k` holds the current sample. The uniform draws come
+/// from a SplitMix64 stream seeded with `seed`.
+///
+/// An infinite weight gives the key `u^0 = 1`, larger than any finite weight can
+/// reach, so those items are always selected. If more than `k` items have infinite
+/// weight, the first `k` of them are kept.
+///
+/// # Arguments
+/// * `items` - `(item, weight)` pairs; items whose weight is zero, negative or NaN
+///   are never selected
+/// * `k` - The sample size
+/// * `seed` - Seed for the random number generator, so samples are reproducible
+///
+/// # Returns
+/// * Up to `k` sampled items, ordered by decreasing key. Fewer than `k` items are
+///   returned only if fewer than `k` items have a positive weight.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::sampling;
+/// #
+/// let items = vec![("a", 1.0), ("b", 5.0), ("c", 0.0), ("d", 2.0)];
+/// let sample = sampling::weighted_reservoir_sample(items.into_iter(), 2, 42);
+/// assert_eq!(sample.len(), 2);
+/// assert!(!sample.contains(&"c"));
+/// ```
+///
+/// # Performance
+/// * Time: O(n log k)
+/// * Space: O(k)
+pub fn weighted_reservoir_sample<T: Clone>(
+    items: impl Iterator<Item = (T, f64)>,
+    k: usize,
+    seed: u64,
+) -> Vec<T> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut draws = 0u64;
+    let mut reservoir: BinaryHeap<Reverse<Entry<T>>> = BinaryHeap::with_capacity(k);
+
+    for (item, weight) in items {
+        if weight.is_nan() || weight <= 0.0 {
+            continue;
+        }
+
+        let key = if weight.is_infinite() {
+            TotalF64(0.0)
+        } else {
+            // The i-th SplitMix64 output, mapped to the open interval (0, 1) so every
+            // finite-weight key is strictly below an infinite-weight key
+            let bits = splitmix64(seed, draws);
+            draws += 1;
+            let u = ((bits >> 11) as f64 + 0.5) / (1u64 << 53) as f64;
+            TotalF64(u.ln() / weight)
+        };
+
+        if reservoir.len() < k {
+            reservoir.push(Reverse(Entry { key, item }));
+        } else if let Some(mut min) = reservoir.peek_mut() {
+            if key > min.0.key {
+                *min = Reverse(Entry { key, item });
+            }
+        }
+    }
+
+    reservoir
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(entry)| entry.item)
+        .collect()
+}
+
+/// A sampled item ordered by its key alone
+#[derive(Debug)]
+struct Entry<T> {
+    key: TotalF64,
+    item: T,
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_zero() {
+        let items = vec![(1, 1.0), (2, 1.0)];
+        assert!(weighted_reservoir_sample(items.into_iter(), 0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_k_larger_than_input() {
+        let items = vec![(1, 1.0), (2, 3.0), (3, 2.0)];
+        let mut sample = weighted_reservoir_sample(items.into_iter(), 10, 7);
+        sample.sort();
+        assert_eq!(sample, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_invalid_weights_are_skipped() {
+        let items = vec![(1, 0.0), (2, -1.0), (3, f64::NAN), (5, 1.0)];
+        assert_eq!(weighted_reservoir_sample(items.into_iter(), 3, 3), vec![5]);
+    }
+
+    #[test]
+    fn test_infinite_weights_are_always_selected() {
+        let mut items: Vec<(usize, f64)> = (0..30).map(|i| (i, 1e6)).collect();
+        items.insert(17, (99, f64::INFINITY));
+
+        for seed in 0..200 {
+            let sample = weighted_reservoir_sample(items.clone().into_iter(), 3, seed);
+            assert_eq!(sample.len(), 3);
+            assert_eq!(sample[0], 99, "seed {}", seed);
+        }
+
+        // With more infinite weights than slots, the first ones are kept
+        let items = vec![
+            (1, 1.0),
+            (2, f64::INFINITY),
+            (3, f64::INFINITY),
+            (4, f64::INFINITY),
+        ];
+        let mut sample = weighted_reservoir_sample(items.into_iter(), 2, 5);
+        sample.sort();
+        assert_eq!(sample, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_without_replacement() {
+        let items: Vec<(usize, f64)> = (0..100).map(|i| (i, 1.0 + (i % 5) as f64)).collect();
+        let mut sample = weighted_reservoir_sample(items.into_iter(), 20, 11);
+        assert_eq!(sample.len(), 20);
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 20);
+    }
+
+    #[test]
+    fn test_same_seed_is_reproducible() {
+        let items: Vec<(usize, f64)> = (0..50).map(|i| (i, (i + 1) as f64)).collect();
+        let a = weighted_reservoir_sample(items.clone().into_iter(), 5, 99);
+        let b = weighted_reservoir_sample(items.clone().into_iter(), 5, 99);
+        let c = weighted_reservoir_sample(items.into_iter(), 5, 100);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_high_weight_items_are_favoured() {
+        // Item 0 has weight 20, items 1..10 have weight 1
+        let items: Vec<(usize, f64)> = (0..10)
+            .map(|i| (i, if i == 0 { 20.0 } else { 1.0 }))
+            .collect();
+
+        let mut counts = [0usize; 10];
+        for seed in 0..2_000 {
+            for item in weighted_reservoir_sample(items.clone().into_iter(), 1, seed) {
+                counts[item] += 1;
+            }
+        }
+
+        // P(item 0 first) = 20 / 29 ≈ 0.69, P(any other item) = 1 / 29 ≈ 0.034
+        assert!(counts[0] > 1_200, "heavy item sampled {} times", counts[0]);
+        for &count in &counts[1..] {
+            assert!(count < 200, "light item sampled {} times", count);
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use super::TotalF64;
+use std::collections::{BTreeMap, VecDeque};
+
+/// Computes the median of every contiguous window of `window` values.
+///
+/// The window is kept in two ordered multisets: the lower half and the upper half of
+/// its values. Sliding the window removes one value and inserts another, after which
+/// the halves are rebalanced so the median can be read from their boundary. For even
+/// windows the median is the mean of the two middle values.
+///
+/// Values are ordered by `f64::total_cmp`, so NaN is treated as an extreme value:
+/// above `+∞`, or below `-∞` if its sign bit is set. A window's median is then NaN
+/// only if a NaN lands in the middle, and it does not depend on where the NaN sits
+/// in the window.
+///
+/// # Arguments
+/// * `data` - The series to scan
+/// * `window` - The number of values in each window
+///
+/// # Returns
+/// * The median of `data[i..i + window]` for each `i`, i.e. `data.len() - window + 1`
+///   values. An empty vector if `window` is zero or larger than `data`.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::sliding_window;
+/// #
+/// let medians = sliding_window::sliding_window_median(&[1.0, 3.0, -1.0, -3.0, 5.0], 3);
+/// assert_eq!(medians, vec![1.0, -1.0, -1.0]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n log w) where w is the window size
+/// * Space: O(w)
+pub fn sliding_window_median(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+
+    let mut halves = MedianHalves::default();
+    let mut medians = Vec::with_capacity(data.len() - window + 1);
+    for (i, &value) in data.iter().enumerate() {
+        halves.insert(value);
+        if i >= window {
+            halves.remove(data[i - window]);
+        }
+        if i + 1 >= window {
+            medians.push(halves.median());
+        }
+    }
+    medians
+}
+
+/// Computes the maximum of every contiguous window of `window` values.
+///
+/// A deque holds the indices of values that may still become a window maximum, in
+/// decreasing order of value; each index is pushed and popped at most once.
+///
+/// Values are ordered by `f64::total_cmp`, as in [`sliding_window_median`], so a
+/// NaN is the maximum of every window containing it (unless its sign bit is set, in
+/// which case it sorts below `-∞`).
+///
+/// # Arguments
+/// * `data` - The series to scan
+/// * `window` - The number of values in each window
+///
+/// # Returns
+/// * The maximum of `data[i..i + window]` for each `i`. An empty vector if `window`
+///   is zero or larger than `data`.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::sliding_window;
+/// #
+/// let maxima = sliding_window::sliding_window_max(&[1.0, 3.0, -1.0, -3.0, 5.0], 3);
+/// assert_eq!(maxima, vec![3.0, 3.0, 5.0]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n) amortized
+/// * Space: O(w)
+pub fn sliding_window_max(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+
+    let mut candidates: VecDeque<usize> = VecDeque::with_capacity(window);
+    let mut maxima = Vec::with_capacity(data.len() - window + 1);
+    for (i, &value) in data.iter().enumerate() {
+        // Drop the index that just left the window
+        if candidates.front().is_some_and(|&front| front + window <= i) {
+            candidates.pop_front();
+        }
+        // Smaller values behind the new one can never be a maximum again
+        while candidates
+            .back()
+            .is_some_and(|&back| TotalF64(data[back]) <= TotalF64(value))
+        {
+            candidates.pop_back();
+        }
+        candidates.push_back(i);
+
+        if i + 1 >= window {
+            maxima.push(data[candidates[0]]);
+        }
+    }
+    maxima
+}
+
+/// A multiset split into a lower and an upper half.
+///
+/// Invariants: every value in `low` is at most every value in `high`, and `low` holds
+/// either as many values as `high` or exactly one more.
+#[derive(Debug, Default)]
+struct MedianHalves {
+    low: Multiset,
+    high: Multiset,
+}
+
+impl MedianHalves {
+    fn insert(&mut self, value: f64) {
+        match self.low.max() {
+            Some(max) if TotalF64(value) > TotalF64(max) => self.high.insert(value),
+            _ => self.low.insert(value),
+        }
+        self.rebalance();
+    }
+
+    fn remove(&mut self, value: f64) {
+        if !self.low.remove(value) {
+            self.high.remove(value);
+        }
+        self.rebalance();
+    }
+
+    fn rebalance(&mut self) {
+        while self.low.len > self.high.len + 1 {
+            let max = self.low.pop_max();
+            self.high.insert(max);
+        }
+        while self.high.len > self.low.len {
+            let min = self.high.pop_min();
+            self.low.insert(min);
+        }
+    }
+
+    fn median(&self) -> f64 {
+        let low_max = self.low.max().unwrap_or(f64::NAN);
+        if self.low.len > self.high.len {
+            low_max
+        } else {
+            (low_max + self.high.min().unwrap_or(f64::NAN)) / 2.0
+        }
+    }
+}
+
+/// A sorted multiset of `f64` values stored as value counts
+#[derive(Debug, Default)]
+struct Multiset {
+    counts: BTreeMap<TotalF64, usize>,
+    len: usize,
+}
+
+impl Multiset {
+    fn insert(&mut self, value: f64) {
+        *self.counts.entry(TotalF64(value)).or_insert(0) += 1;
+        self.len += 1;
+    }
+
+    /// Removes one copy of `value`, returning false if it is not present
+    fn remove(&mut self, value: f64) -> bool {
+        let Some(count) = self.counts.get_mut(&TotalF64(value)) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.counts.remove(&TotalF64(value));
+        }
+        self.len -= 1;
+        true
+    }
+
+    fn max(&self) -> Option<f64> {
+        self.counts.keys().next_back().map(|k| k.0)
+    }
+
+    fn min(&self) -> Option<f64> {
+        self.counts.keys().next().map(|k| k.0)
+    }
+
+    fn pop_max(&mut self) -> f64 {
+        let max = self.max().expect("pop_max on empty multiset");
+        self.remove(max);
+        max
+    }
+
+    fn pop_min(&mut self) -> f64 {
+        let min = self.min().expect("pop_min on empty multiset");
+        self.remove(min);
+        min
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_median(data: &[f64], window: usize) -> Vec<f64> {
+        data.windows(window)
+            .map(|w| {
+                let mut sorted = w.to_vec();
+                sorted.sort_by(|a, b| a.total_cmp(b));
+                let mid = window / 2;
+                if window % 2 == 1 {
+                    sorted[mid]
+                } else {
+                    (sorted[mid - 1] + sorted[mid]) / 2.0
+                }
+            })
+            .collect()
+    }
+
+    fn brute_max(data: &[f64], window: usize) -> Vec<f64> {
+        data.windows(window)
+            .map(|w| w.iter().copied().max_by(f64::total_cmp).unwrap())
+            .collect()
+    }
+
+    fn assert_same_values(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!(
+                a == e || (a.is_nan() && e.is_nan()),
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_median_with_nan_uses_total_order() {
+        // NaN sorts above every number, so it only shifts which value is the median
+        let data = [1.0, f64::NAN, 3.0, 2.0, 5.0];
+        assert_eq!(sliding_window_median(&data, 3), vec![3.0, 3.0, 3.0]);
+
+        let base = [4.0, -1.0, 7.0, 2.0, 9.0, 0.5, 3.0, 6.0, -2.0, 8.0];
+        for nan in [f64::NAN, -f64::NAN] {
+            for position in 0..base.len() {
+                let mut data = base.to_vec();
+                data[position] = nan;
+                for window in [2, 3, 4, 5] {
+                    assert_same_values(
+                        &sliding_window_median(&data, window),
+                        &brute_median(&data, window),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_with_nan_uses_total_order() {
+        for data in [
+            [f64::NAN, 1.0, 2.0],
+            [1.0, f64::NAN, 2.0],
+            [1.0, 2.0, f64::NAN],
+        ] {
+            assert!(sliding_window_max(&data, 3)[0].is_nan(), "{:?}", data);
+        }
+
+        let base = [4.0, -1.0, 7.0, 2.0, 9.0, 0.5, 3.0, 6.0, -2.0, 8.0];
+        for nan in [f64::NAN, -f64::NAN] {
+            for position in 0..base.len() {
+                let mut data = base.to_vec();
+                data[position] = nan;
+                for window in [1, 2, 3, 5] {
+                    assert_same_values(
+                        &sliding_window_max(&data, window),
+                        &brute_max(&data, window),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_degenerate_windows() {
+        let data = [1.0, 2.0, 3.0];
+        assert!(sliding_window_median(&data, 0).is_empty());
+        assert!(sliding_window_median(&data, 4).is_empty());
+        assert!(sliding_window_max(&data, 0).is_empty());
+        assert!(sliding_window_max(&data, 4).is_empty());
+        assert!(sliding_window_median(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn test_window_of_one_is_identity() {
+        let data = [4.0, -2.0, 7.5, 0.0];
+        assert_eq!(sliding_window_median(&data, 1), data.to_vec());
+        assert_eq!(sliding_window_max(&data, 1), data.to_vec());
+    }
+
+    #[test]
+    fn test_window_of_three() {
+        let data = [5.0, 1.0, 4.0, 2.0, 8.0, 3.0, 3.0];
+        assert_eq!(
+            sliding_window_median(&data, 3),
+            vec![4.0, 2.0, 4.0, 3.0, 3.0]
+        );
+        assert_eq!(sliding_window_max(&data, 3), vec![5.0, 4.0, 8.0, 8.0, 8.0]);
+        assert_eq!(sliding_window_median(&data, 3), brute_median(&data, 3));
+        assert_eq!(sliding_window_max(&data, 3), brute_max(&data, 3));
+    }
+
+    #[test]
+    fn test_even_window_averages_middle_values() {
+        let data = [1.0, 3.0, 2.0, 6.0, 5.0];
+        assert_eq!(sliding_window_median(&data, 2), vec![2.0, 2.5, 4.0, 5.5]);
+        assert_eq!(sliding_window_median(&data, 4), vec![2.5, 4.0]);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let data = [2.0, 2.0, 2.0, 1.0, 2.0, 2.0, 3.0, 3.0];
+        for window in 1..=data.len() {
+            assert_eq!(
+                sliding_window_median(&data, window),
+                brute_median(&data, window)
+            );
+            assert_eq!(sliding_window_max(&data, window), brute_max(&data, window));
+        }
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let data: Vec<f64> = (0..300)
+            .map(|i| ((i * 7919 % 97) as f64) / 4.0 - 10.0)
+            .collect();
+        for window in [2, 3, 5, 10, 33, 300] {
+            assert_eq!(
+                sliding_window_median(&data, window),
+                brute_median(&data, window)
+            );
+            assert_eq!(sliding_window_max(&data, window), brute_max(&data, window));
+        }
+    }
+}
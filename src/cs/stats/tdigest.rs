@@ -0,0 +1,417 @@
+use crate::cs::error::{Error, Result};
+use std::borrow::Cow;
+use std::f64::consts::PI;
+
+/// The compression used by [`TDigest::default`].
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+/// A cluster of nearby values, summarised by their mean and count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A t-digest: a streaming sketch answering approximate quantile queries in bounded
+/// memory.
+///
+/// Values are summarised by centroids sorted by mean. The arcsine scale function
+/// limits how much weight a centroid may absorb based on where it falls in the
+/// distribution, so centroids near the tails stay small and extreme quantiles remain
+/// accurate while the middle of the distribution is summarised more coarsely. New
+/// values are buffered and merged into the centroids in batches. The number of
+/// centroids stays below the compression parameter however many values are pushed.
+///
+/// Quantiles are estimated by linear interpolation between centroid means, with
+/// the exact minimum and maximum anchoring both ends.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::TDigest;
+/// #
+/// let mut digest = TDigest::default();
+/// for i in 0..10_000 {
+///     digest.push(i as f64);
+/// }
+///
+/// let median = digest.quantile(0.5).unwrap();
+/// assert!((median - 5_000.0).abs() < 50.0);
+/// assert_eq!(digest.quantile(1.0).unwrap(), 9_999.0);
+/// ```
+///
+/// # Performance
+/// * `push`: O(1) amortized, plus O(δ log δ) per batch merge
+/// * `quantile`: O(δ), or O(δ log δ) if values are still buffered
+/// * Space: O(δ), where δ is the compression
+#[derive(Debug, Clone)]
+pub struct TDigest {
+    /// Upper bound on the number of centroids; larger values are more accurate
+    compression: f64,
+    /// Merged centroids, sorted by mean
+    centroids: Vec<Centroid>,
+    /// Values and centroids waiting to be merged
+    buffer: Vec<Centroid>,
+    /// Number of values summarised, including buffered ones
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::with_valid_compression(DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    /// Creates an empty digest.
+    ///
+    /// # Arguments
+    /// * `compression` - Bounds the number of centroids kept. Around 100 gives
+    ///   quantiles within a fraction of a percent on typical data.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if `compression` is not finite or is less than 1
+    pub fn new(compression: f64) -> Result<Self> {
+        if !(compression.is_finite() && compression >= 1.0) {
+            return Err(Error::invalid_input(format!(
+                "compression must be a finite number of at least 1, got {}",
+                compression
+            )));
+        }
+        Ok(Self::with_valid_compression(compression))
+    }
+
+    fn with_valid_compression(compression: f64) -> Self {
+        Self {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Returns the number of values summarised.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns true if no values have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Adds a value to the digest.
+    ///
+    /// Non-finite values (NaN and ±∞) are ignored: a centroid mean cannot absorb an
+    /// infinity without becoming NaN.
+    pub fn push(&mut self, x: f64) {
+        if !x.is_finite() {
+            return;
+        }
+
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+        self.count += 1;
+        self.buffer.push(Centroid {
+            mean: x,
+            weight: 1.0,
+        });
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.flush();
+        }
+    }
+
+    /// Folds another digest into this one, as if its values had been pushed here.
+    ///
+    /// The result keeps this digest's compression.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.count += other.count;
+        self.buffer.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.flush();
+    }
+
+    /// Estimates the q-th quantile of the values pushed so far.
+    ///
+    /// # Arguments
+    /// * `q` - The quantile to estimate, between 0 and 1 inclusive
+    ///
+    /// # Returns
+    /// * `Result<f64>` - The estimate; exact at `q = 0` and `q = 1`
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if the digest is empty or `q` is outside [0, 1]
+    pub fn quantile(&self, q: f64) -> Result<f64> {
+        if self.is_empty() {
+            return Err(Error::invalid_input("quantile of an empty digest"));
+        }
+        if !(0.0..=1.0).contains(&q) {
+            return Err(Error::invalid_input(format!(
+                "quantile must be between 0 and 1, got {}",
+                q
+            )));
+        }
+
+        let centroids = if self.buffer.is_empty() {
+            Cow::Borrowed(&self.centroids)
+        } else {
+            let pending = self.centroids.iter().chain(&self.buffer).copied().collect();
+            Cow::Owned(compress(self.compression, pending))
+        };
+
+        // Each centroid's mean is placed at the midpoint of its weight, and the
+        // estimate interpolates between neighbouring midpoints
+        let total = self.count as f64;
+        let target = q * total;
+
+        let first = centroids[0];
+        if target <= first.weight / 2.0 {
+            let fraction = target / (first.weight / 2.0);
+            return Ok(self.min + (first.mean - self.min) * fraction);
+        }
+
+        let mut cumulative = 0.0;
+        for pair in centroids.windows(2) {
+            let (left, right) = (pair[0], pair[1]);
+            let left_mid = cumulative + left.weight / 2.0;
+            let right_mid = cumulative + left.weight + right.weight / 2.0;
+            if target <= right_mid {
+                let fraction = (target - left_mid) / (right_mid - left_mid);
+                return Ok(left.mean + (right.mean - left.mean) * fraction);
+            }
+            cumulative += left.weight;
+        }
+
+        let last = centroids[centroids.len() - 1];
+        let last_mid = total - last.weight / 2.0;
+        let fraction = ((target - last_mid) / (last.weight / 2.0)).clamp(0.0, 1.0);
+        Ok(last.mean + (self.max - last.mean) * fraction)
+    }
+
+    /// Number of buffered entries that triggers a merge.
+    fn buffer_capacity(&self) -> usize {
+        (self.compression * 5.0).ceil() as usize
+    }
+
+    /// Merges the buffer into the centroids.
+    fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut pending = std::mem::take(&mut self.centroids);
+        pending.append(&mut self.buffer);
+        self.centroids = compress(self.compression, pending);
+    }
+}
+
+/// Sorts `items` by mean and greedily merges neighbours while each merged centroid
+/// stays within one unit of the scale function.
+fn compress(compression: f64, mut items: Vec<Centroid>) -> Vec<Centroid> {
+    items.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+    let total: f64 = items.iter().map(|c| c.weight).sum();
+
+    let mut merged = Vec::new();
+    let mut current = items[0];
+    let mut weight_before = 0.0;
+    let mut weight_limit = total * next_quantile_limit(compression, 0.0);
+
+    for &next in &items[1..] {
+        if weight_before + current.weight + next.weight <= weight_limit {
+            let weight = current.weight + next.weight;
+            current.mean += (next.mean - current.mean) * next.weight / weight;
+            current.weight = weight;
+        } else {
+            weight_before += current.weight;
+            merged.push(current);
+            weight_limit = total * next_quantile_limit(compression, weight_before / total);
+            current = next;
+        }
+    }
+    merged.push(current);
+    merged
+}
+
+/// Returns the largest quantile a centroid starting at quantile `q` may extend to,
+/// using the arcsine scale function `k(q) = δ / 2π · asin(2q - 1)`.
+fn next_quantile_limit(compression: f64, q: f64) -> f64 {
+    let scale = compression / (2.0 * PI);
+    let k = scale * (2.0 * q - 1.0).clamp(-1.0, 1.0).asin();
+    let k_next = (k + 1.0).min(compression / 4.0);
+    ((k_next / scale).sin() + 1.0) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cs::stats::quantile::{percentile, InterpolationMethod};
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    fn exponential_sample(n: usize, seed: u64) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n).map(|_| -(1.0 - rng.gen::<f64>()).ln()).collect()
+    }
+
+    fn assert_relative_error(actual: f64, expected: f64, tolerance: f64) {
+        let error = ((actual - expected) / expected).abs();
+        assert!(
+            error < tolerance,
+            "expected {} within {:.2}%, got {} ({:.3}% off)",
+            expected,
+            tolerance * 100.0,
+            actual,
+            error * 100.0
+        );
+    }
+
+    #[test]
+    fn test_matches_exact_quantiles_on_large_stream() {
+        let data = exponential_sample(100_000, 42);
+        let mut digest = TDigest::default();
+        for &x in &data {
+            digest.push(x);
+        }
+
+        assert_eq!(digest.count(), 100_000);
+        for q in [0.5, 0.95, 0.99] {
+            let exact = percentile(&data, q * 100.0, InterpolationMethod::Linear).unwrap();
+            assert_relative_error(digest.quantile(q).unwrap(), exact, 0.01);
+        }
+    }
+
+    #[test]
+    fn test_memory_is_bounded() {
+        let mut digest = TDigest::new(50.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..200_000 {
+            digest.push(rng.gen_range(-1e6..1e6));
+        }
+        digest.flush();
+        assert!(
+            digest.centroids.len() <= 50,
+            "{} centroids",
+            digest.centroids.len()
+        );
+        assert!(digest.buffer.is_empty());
+    }
+
+    #[test]
+    fn test_merge_matches_single_digest() {
+        let data = exponential_sample(60_000, 7);
+        let mut left = TDigest::default();
+        let mut right = TDigest::default();
+        for (i, &x) in data.iter().enumerate() {
+            if i % 3 == 0 {
+                left.push(x);
+            } else {
+                right.push(x);
+            }
+        }
+
+        left.merge(&right);
+        assert_eq!(left.count(), 60_000);
+        for q in [0.5, 0.95] {
+            let exact = percentile(&data, q * 100.0, InterpolationMethod::Linear).unwrap();
+            assert_relative_error(left.quantile(q).unwrap(), exact, 0.01);
+        }
+
+        // Merging an empty digest changes nothing
+        let before = left.quantile(0.5).unwrap();
+        left.merge(&TDigest::default());
+        assert_eq!(left.quantile(0.5).unwrap(), before);
+    }
+
+    #[test]
+    fn test_extremes_are_exact() {
+        let mut values: Vec<f64> = (0..5_000).map(|i| i as f64 * 0.5 - 100.0).collect();
+        values.shuffle(&mut StdRng::seed_from_u64(11));
+        let mut digest = TDigest::new(20.0).unwrap();
+        for &x in &values {
+            digest.push(x);
+        }
+
+        assert_eq!(digest.quantile(0.0).unwrap(), -100.0);
+        assert_eq!(digest.quantile(1.0).unwrap(), 2_399.5);
+    }
+
+    #[test]
+    fn test_small_stream() {
+        let mut digest = TDigest::default();
+        for x in [3.0, 1.0, 5.0, 2.0, 4.0] {
+            digest.push(x);
+        }
+
+        assert_eq!(digest.quantile(0.5).unwrap(), 3.0);
+        assert_eq!(digest.quantile(0.0).unwrap(), 1.0);
+        assert_eq!(digest.quantile(1.0).unwrap(), 5.0);
+
+        let quantiles: Vec<f64> = (0..=10)
+            .map(|i| digest.quantile(i as f64 / 10.0).unwrap())
+            .collect();
+        assert!(quantiles.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_single_value() {
+        let mut digest = TDigest::default();
+        digest.push(7.5);
+        for q in [0.0, 0.3, 1.0] {
+            assert_eq!(digest.quantile(q).unwrap(), 7.5);
+        }
+    }
+
+    #[test]
+    fn test_non_finite_values_are_ignored() {
+        let mut digest = TDigest::default();
+        digest.push(f64::NAN);
+        digest.push(f64::INFINITY);
+        digest.push(f64::NEG_INFINITY);
+        assert!(digest.is_empty());
+
+        // Enough infinities to force several batch merges alongside finite values
+        for i in 0..2_000 {
+            digest.push(i as f64);
+            digest.push(f64::INFINITY);
+            digest.push(f64::NEG_INFINITY);
+        }
+        assert_eq!(digest.count(), 2_000);
+        assert_eq!(digest.quantile(0.0).unwrap(), 0.0);
+        assert_eq!(digest.quantile(1.0).unwrap(), 1_999.0);
+        for q in [0.1, 0.5, 0.9] {
+            let estimate = digest.quantile(q).unwrap();
+            assert!(estimate.is_finite(), "q = {} gave {}", q, estimate);
+            assert!((estimate - q * 1_999.0).abs() < 20.0);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(
+            TDigest::default().quantile(0.5),
+            Err(Error::InvalidInput(_))
+        ));
+
+        let mut digest = TDigest::default();
+        digest.push(1.0);
+        assert!(matches!(digest.quantile(1.5), Err(Error::InvalidInput(_))));
+        assert!(matches!(digest.quantile(-0.1), Err(Error::InvalidInput(_))));
+
+        for compression in [0.0, 0.5, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                TDigest::new(compression),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::cs::error::{Error, Result};
+
+/// Computes the weighted arithmetic mean `Σ wᵢxᵢ / Σ wᵢ`.
+///
+/// Values with zero weight are ignored, even if they are infinite.
+///
+/// # Arguments
+/// * `values` - The values to average
+/// * `weights` - One non-negative weight per value
+///
+/// # Returns
+/// * `Result<f64>` - The weighted mean
+///
+/// # Errors
+/// * `Error::InvalidInput` if the slices differ in length or are empty, if a value
+///   is NaN, if a weight is negative or not finite, if all weights are zero, or if
+///   the total weight overflows
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::weighted;
+/// #
+/// let mean = weighted::weighted_mean(&[1.0, 2.0, 4.0], &[1.0, 1.0, 2.0]).unwrap();
+/// assert_eq!(mean, 2.75);
+/// ```
+///
+/// # Performance
+/// * Time: O(n)
+/// * Space: O(1)
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> Result<f64> {
+    let total = validate(values, weights)?;
+    // Skip zero weights so an infinite value with no weight cannot turn the sum into NaN
+    let sum: f64 = values
+        .iter()
+        .zip(weights)
+        .filter(|&(_, &w)| w > 0.0)
+        .map(|(v, w)| v * w)
+        .sum();
+    Ok(sum / total)
+}
+
+/// Computes the q-th weighted quantile by interpolating over cumulative weights.
+///
+/// Values are sorted and each is placed at the midpoint of its share of the total
+/// weight, `(Wᵢ - wᵢ / 2) / W` where `Wᵢ` is the cumulative weight up to and including
+/// it. These positions are then rescaled so the smallest value sits at 0 and the
+/// largest at 1, and the quantile is interpolated linearly between them. With equal
+/// weights this reproduces the unweighted linear-interpolation quantile, i.e.
+/// [`percentile`](super::quantile::percentile) with
+/// [`InterpolationMethod::Linear`](super::quantile::InterpolationMethod::Linear).
+/// Values with zero weight are ignored.
+///
+/// # Arguments
+/// * `values` - The values, in any order
+/// * `weights` - One non-negative weight per value
+/// * `q` - The quantile to compute, between 0 and 1 inclusive
+///
+/// # Returns
+/// * `Result<f64>` - The weighted quantile
+///
+/// # Errors
+/// * `Error::InvalidInput` if the slices differ in length or are empty, if a value
+///   is NaN, if a weight is negative or not finite, if all weights are zero, if the
+///   total weight overflows, or if `q` is outside [0, 1]
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::stats::weighted;
+/// #
+/// let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let equal = weighted::weighted_quantile(&values, &[1.0; 5], 0.5).unwrap();
+/// assert_eq!(equal, 3.0);
+///
+/// let skewed = weighted::weighted_quantile(&values, &[1.0, 1.0, 1.0, 1.0, 6.0], 0.5).unwrap();
+/// assert!(skewed > 4.0);
+/// ```
+///
+/// # Performance
+/// * Time: O(n log n)
+/// * Space: O(n)
+pub fn weighted_quantile(values: &[f64], weights: &[f64], q: f64) -> Result<f64> {
+    validate(values, weights)?;
+    if !(0.0..=1.0).contains(&q) {
+        return Err(Error::invalid_input(format!(
+            "quantile must be between 0 and 1, got {}",
+            q
+        )));
+    }
+
+    let mut points: Vec<(f64, f64)> = values
+        .iter()
+        .copied()
+        .zip(weights.iter().copied())
+        .filter(|&(_, w)| w > 0.0)
+        .collect();
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    if points.len() == 1 {
+        return Ok(points[0].0);
+    }
+
+    // Midpoint of each value's share of the cumulative weight
+    let mut cumulative = 0.0;
+    let mut positions = Vec::with_capacity(points.len());
+    for &(_, w) in &points {
+        positions.push(cumulative + w / 2.0);
+        cumulative += w;
+    }
+
+    // Rescale so the extreme values sit exactly at q = 0 and q = 1
+    let first = positions[0];
+    let span = positions[positions.len() - 1] - first;
+    let target = first + q * span;
+
+    // First position at or beyond the target; the quantile lies in the gap before it
+    let upper = positions
+        .partition_point(|&p| p < target)
+        .clamp(1, points.len() - 1);
+    let (p_low, p_high) = (positions[upper - 1], positions[upper]);
+    let (v_low, v_high) = (points[upper - 1].0, points[upper].0);
+    let fraction = ((target - p_low) / (p_high - p_low)).clamp(0.0, 1.0);
+    Ok(v_low + (v_high - v_low) * fraction)
+}
+
+/// Validates paired values and weights, returning the total weight.
+fn validate(values: &[f64], weights: &[f64]) -> Result<f64> {
+    if values.len() != weights.len() {
+        return Err(Error::invalid_input(format!(
+            "values and weights must have the same length, got {} and {}",
+            values.len(),
+            weights.len()
+        )));
+    }
+    if values.is_empty() {
+        return Err(Error::invalid_input("values must not be empty"));
+    }
+    if values.iter().any(|v| v.is_nan()) {
+        return Err(Error::invalid_input("values must not contain NaN"));
+    }
+    if weights.iter().any(|w| !(w.is_finite() && *w >= 0.0)) {
+        return Err(Error::invalid_input(
+            "weights must be finite and non-negative",
+        ));
+    }
+
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(Error::invalid_input("weights must not all be zero"));
+    }
+    if !total.is_finite() {
+        return Err(Error::invalid_input("total weight overflows f64"));
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cs::stats::quantile::{percentile, InterpolationMethod};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        assert_close(
+            weighted_mean(&[1.0, 2.0, 4.0], &[1.0, 1.0, 2.0]).unwrap(),
+            2.75,
+        );
+        // Zero-weight values do not contribute
+        assert_close(weighted_mean(&[1.0, 100.0], &[1.0, 0.0]).unwrap(), 1.0);
+        assert_close(
+            weighted_mean(&[1.0, f64::INFINITY, 3.0], &[1.0, 0.0, 1.0]).unwrap(),
+            2.0,
+        );
+    }
+
+    #[test]
+    fn test_equal_weights_match_unweighted_mean() {
+        let values = [3.5, -1.0, 8.0, 2.25, 0.5];
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        for weight in [1.0, 0.2, 7.0] {
+            assert_close(weighted_mean(&values, &[weight; 5]).unwrap(), mean);
+        }
+    }
+
+    #[test]
+    fn test_equal_weights_match_unweighted_quantile() {
+        let values = [10.0, 7.0, 4.0, 3.0, 2.0, 1.0, 12.5, -3.0];
+        for q in [0.0, 0.1, 0.25, 0.5, 0.6, 0.75, 0.99, 1.0] {
+            let expected = percentile(&values, q * 100.0, InterpolationMethod::Linear).unwrap();
+            for weight in [1.0, 3.0] {
+                assert_close(
+                    weighted_quantile(&values, &[weight; 8], q).unwrap(),
+                    expected,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_upweighted_tail_shifts_quantile() {
+        let values: Vec<f64> = (1..=10).map(f64::from).collect();
+        let equal = vec![1.0; 10];
+        let mut tail_heavy = equal.clone();
+        tail_heavy[8] = 10.0;
+        tail_heavy[9] = 10.0;
+
+        for q in [0.25, 0.5, 0.75] {
+            let unweighted = weighted_quantile(&values, &equal, q).unwrap();
+            let weighted = weighted_quantile(&values, &tail_heavy, q).unwrap();
+            assert!(
+                weighted > unweighted,
+                "q = {}: {} <= {}",
+                q,
+                weighted,
+                unweighted
+            );
+        }
+        assert!(weighted_quantile(&values, &tail_heavy, 0.5).unwrap() > 8.0);
+    }
+
+    #[test]
+    fn test_quantile_extremes() {
+        let values = [5.0, 1.0, 3.0, 9.0];
+        let weights = [2.0, 0.5, 4.0, 1.0];
+        assert_eq!(weighted_quantile(&values, &weights, 0.0).unwrap(), 1.0);
+        assert_eq!(weighted_quantile(&values, &weights, 1.0).unwrap(), 9.0);
+    }
+
+    #[test]
+    fn test_quantile_is_monotone() {
+        let values = [4.0, 1.0, 7.0, 2.0, 9.0, 3.0];
+        let weights = [0.5, 2.0, 1.0, 3.0, 0.25, 1.5];
+        let quantiles: Vec<f64> = (0..=20)
+            .map(|i| weighted_quantile(&values, &weights, i as f64 / 20.0).unwrap())
+            .collect();
+        assert!(quantiles.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_quantile_ignores_zero_weights() {
+        let values = [1.0, 2.0, 3.0, 100.0];
+        assert_eq!(
+            weighted_quantile(&values, &[1.0, 1.0, 1.0, 0.0], 1.0).unwrap(),
+            3.0
+        );
+        assert_eq!(
+            weighted_quantile(&values, &[0.0, 0.0, 2.0, 0.0], 0.3).unwrap(),
+            3.0
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let is_invalid = |r: Result<f64>| matches!(r, Err(Error::InvalidInput(_)));
+
+        assert!(is_invalid(weighted_mean(&[1.0, 2.0], &[1.0])));
+        assert!(is_invalid(weighted_mean(&[], &[])));
+        assert!(is_invalid(weighted_mean(&[1.0], &[-1.0])));
+        assert!(is_invalid(weighted_mean(&[1.0], &[f64::NAN])));
+        assert!(is_invalid(weighted_mean(&[f64::NAN], &[1.0])));
+        assert!(is_invalid(weighted_mean(&[1.0, 2.0], &[0.0, 0.0])));
+        assert!(is_invalid(weighted_mean(&[1.0, 2.0], &[1e308, 1e308])));
+        assert!(is_invalid(weighted_quantile(
+            &[1.0, 2.0],
+            &[1e308, 1e308],
+            0.5
+        )));
+
+        assert!(is_invalid(weighted_quantile(&[1.0], &[1.0], 1.5)));
+        assert!(is_invalid(weighted_quantile(&[1.0], &[1.0], -0.1)));
+        assert!(is_invalid(weighted_quantile(&[1.0, 2.0], &[1.0], 0.5)));
+    }
+}
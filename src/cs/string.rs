@@ -1,25 +1,40 @@
 pub mod aho_corasick;
+pub mod bitap;
 pub mod boyer_moore;
+pub mod huffman;
 pub mod kmp;
+pub mod lsh;
 pub mod manacher;
+pub mod ngram;
 pub mod rabin_karp;
 pub mod rolling_hash;
+pub mod similarity;
 pub mod suffix_array;
 pub mod suffix_automaton;
 pub mod suffix_tree;
+pub mod tokenize;
 pub mod z_algorithm;
 
 // Re-export types
-pub use aho_corasick::{AhoCorasick, Match, MatchConfig};
+pub use aho_corasick::{AhoCorasick, Match, MatchConfig, StreamingAcMatcher};
+pub use huffman::HuffmanTree;
+pub use lsh::{LshIndex, MinHash, MinHashSignature};
 pub use rolling_hash::RollingHash;
 pub use suffix_array::{SearchResult, SuffixArray};
 pub use suffix_automaton::SuffixAutomaton;
 pub use suffix_tree::{SuffixNode, SuffixTree};
 
 // Re-export string matching functions
+pub use bitap::{find_all as bitap_find_all, find_first as bitap_find_first};
 pub use boyer_moore::{find_all as boyer_moore_find_all, find_first as boyer_moore_find_first};
 pub use kmp::{find_all as kmp_find_all, find_first as kmp_find_first};
 pub use manacher::longest_palindrome;
+pub use ngram::{char_ngrams, padded_char_ngrams, padded_word_ngrams, word_ngrams};
 pub use rabin_karp::{find_all as rabin_karp_find_all, find_first as rabin_karp_find_first};
+pub use similarity::{cosine_sparse, hamming_bits, jaccard};
 pub use suffix_array::{find_all as suffix_array_find_all, find_first as suffix_array_find_first};
+pub use tokenize::{
+    regex_word as tokenize_words, remove_stopwords, sentences as tokenize_sentences,
+    whitespace as tokenize_whitespace,
+};
 pub use z_algorithm::{find_all as z_algorithm_find_all, find_first as z_algorithm_find_first};
@@ -220,9 +220,9 @@ impl AhoCorasick {
             || text[..start]
                 .chars()
                 .next_back()
-                .map_or(true, is_boundary_char);
+                .is_none_or(is_boundary_char);
         let after_is_boundary =
-            end >= text.len() || text[end..].chars().next().map_or(true, is_boundary_char);
+            end >= text.len() || text[end..].chars().next().is_none_or(is_boundary_char);
 
         before_is_boundary && after_is_boundary
     }
@@ -290,6 +290,142 @@ impl AhoCorasick {
     }
 }
 
+/// Incremental Aho-Corasick search over a byte stream delivered in chunks.
+///
+/// The matcher keeps the automaton state and the number of bytes consumed between
+/// calls to [`push`](Self::push), so pattern occurrences that straddle chunk
+/// boundaries are found and reported with absolute byte offsets into the whole
+/// stream. A UTF-8 sequence split across chunks is buffered until it is complete;
+/// bytes that are not valid UTF-8 cannot be part of any pattern and reset the search.
+///
+/// Word-boundary checks and longest-match filtering need to look past the end of
+/// a match, so automata configured with either option are not supported.
+///
+/// # Example
+/// ```
+/// use blocks::cs::string::{AhoCorasick, StreamingAcMatcher};
+///
+/// let ac = AhoCorasick::new(vec!["he".to_string(), "she".to_string()]).unwrap();
+/// let mut stream = StreamingAcMatcher::new(&ac).unwrap();
+///
+/// assert!(stream.push(b"s").is_empty());
+/// let matches = stream.push(b"he");
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!((matches[0].start, matches[0].end), (0, 3));
+/// assert_eq!((matches[1].start, matches[1].end), (1, 3));
+/// ```
+#[derive(Debug)]
+pub struct StreamingAcMatcher<'a> {
+    /// The automaton being run.
+    ac: &'a AhoCorasick,
+    /// Current automaton state.
+    state: usize,
+    /// Number of stream bytes consumed, including buffered ones.
+    offset: usize,
+    /// Trailing bytes of an incomplete UTF-8 sequence from the previous chunk.
+    pending: Vec<u8>,
+}
+
+impl<'a> StreamingAcMatcher<'a> {
+    /// Creates a streaming matcher positioned at the start of a new stream.
+    ///
+    /// # Errors
+    /// * `Error::Unsupported` if the automaton uses a boundary checker or
+    ///   `longest_match_only`
+    pub fn new(ac: &'a AhoCorasick) -> Result<Self> {
+        if ac.config.boundary_checker.is_some() || ac.config.longest_match_only {
+            return Err(Error::Unsupported(
+                "streaming search does not support boundary checks or longest-match filtering"
+                    .to_string(),
+            ));
+        }
+        Ok(Self {
+            ac,
+            state: ac.root,
+            offset: 0,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Feeds the next chunk of the stream and returns the matches that end inside it.
+    ///
+    /// Matches are reported in the same order as [`AhoCorasick::find_all`], with
+    /// `start` and `end` measured from the beginning of the stream.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Match> {
+        let mut matches = Vec::new();
+        // Byte offset in the stream of the first byte that is fed below
+        let base = self.offset - self.pending.len();
+        self.offset += chunk.len();
+
+        if self.pending.is_empty() {
+            self.pending = self.feed(chunk, base, &mut matches).to_vec();
+        } else {
+            let mut bytes = std::mem::take(&mut self.pending);
+            bytes.extend_from_slice(chunk);
+            self.pending = self.feed(&bytes, base, &mut matches).to_vec();
+        }
+        matches
+    }
+
+    /// Returns the number of bytes pushed so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Resets the matcher to the start of a new stream.
+    pub fn reset(&mut self) {
+        self.state = self.ac.root;
+        self.offset = 0;
+        self.pending.clear();
+    }
+
+    /// Runs the automaton over `bytes`, which start at stream offset `base`, and
+    /// returns the trailing bytes of an incomplete UTF-8 sequence, if any.
+    fn feed<'b>(
+        &mut self,
+        mut bytes: &'b [u8],
+        mut base: usize,
+        matches: &mut Vec<Match>,
+    ) -> &'b [u8] {
+        loop {
+            let (valid, rest) = match std::str::from_utf8(bytes) {
+                Ok(text) => (text, None),
+                Err(e) => {
+                    // The prefix up to valid_up_to is guaranteed to be valid UTF-8
+                    let text = std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default();
+                    (text, Some(e))
+                }
+            };
+
+            for (i, ch) in valid.char_indices() {
+                self.state = self.ac.find_next_state(self.state, ch);
+                let end = base + i + ch.len_utf8();
+                for &pattern_idx in &self.ac.nodes[self.state].output {
+                    matches.push(Match {
+                        pattern_index: pattern_idx,
+                        start: end - self.ac.patterns[pattern_idx].len(),
+                        end,
+                    });
+                }
+            }
+
+            let Some(error) = rest else {
+                return &[];
+            };
+            let consumed = error.valid_up_to();
+            match error.error_len() {
+                // Incomplete sequence at the end: wait for the next chunk
+                None => return &bytes[consumed..],
+                Some(invalid) => {
+                    self.state = self.ac.root;
+                    bytes = &bytes[consumed + invalid..];
+                    base += consumed + invalid;
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -317,8 +453,10 @@ mod tests {
         assert_eq!(matches.len(), 4);
 
         // With boundary checker => only "she" at start is valid.
-        let mut config = MatchConfig::default();
-        config.boundary_checker = Some(Arc::new(|c: char| !c.is_alphanumeric()));
+        let config = MatchConfig {
+            boundary_checker: Some(Arc::new(|c: char| !c.is_alphanumeric())),
+            ..Default::default()
+        };
 
         let ac = AhoCorasick::with_config(patterns.clone(), config).unwrap();
         let matches: Vec<_> = ac.find_all("she sells seashells").collect();
@@ -341,8 +479,10 @@ mod tests {
         assert_eq!(matches.len(), 3);
 
         // Longest match only => "ant colony" and "colony".
-        let mut config = MatchConfig::default();
-        config.longest_match_only = true;
+        let config = MatchConfig {
+            longest_match_only: true,
+            ..Default::default()
+        };
         let ac = AhoCorasick::with_config(patterns.clone(), config).unwrap();
         let matches: Vec<_> = ac.find_all("ant colony").collect();
         assert_eq!(matches.len(), 2);
@@ -362,10 +502,96 @@ mod tests {
         assert_eq!(matches.len(), 3);
 
         // Longest match only => "🦀🔧" and "🔧".
-        let mut config = MatchConfig::default();
-        config.longest_match_only = true;
+        let config = MatchConfig {
+            longest_match_only: true,
+            ..Default::default()
+        };
         let ac = AhoCorasick::with_config(patterns, config).unwrap();
         let matches: Vec<_> = ac.find_all("🦀🔧").collect();
         assert_eq!(matches.len(), 2);
     }
+
+    fn chunked_matches(ac: &AhoCorasick, text: &[u8], chunk_size: usize) -> Vec<Match> {
+        let mut stream = StreamingAcMatcher::new(ac).unwrap();
+        let matches: Vec<Match> = text
+            .chunks(chunk_size)
+            .flat_map(|chunk| stream.push(chunk))
+            .collect();
+        assert_eq!(stream.offset(), text.len());
+        matches
+    }
+
+    #[test]
+    fn test_streaming_single_byte_chunks() {
+        let patterns: Vec<String> = vec!["he", "she", "his", "hers", "sea"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let ac = AhoCorasick::new(patterns).unwrap();
+        let text = "she sells seashells; his hers ushers";
+
+        let expected: Vec<_> = ac.find_all(text).collect();
+        assert!(!expected.is_empty());
+        for chunk_size in [1, 2, 3, 7, text.len()] {
+            assert_eq!(
+                chunked_matches(&ac, text.as_bytes(), chunk_size),
+                expected,
+                "chunk size {}",
+                chunk_size
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_unicode_split_across_chunks() {
+        let patterns: Vec<String> = vec!["🦀", "🦀🔧", "🔧", "añ"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let ac = AhoCorasick::new(patterns).unwrap();
+        let text = "x🦀🔧 mañana 🔧🦀";
+
+        let expected: Vec<_> = ac.find_all(text).collect();
+        assert_eq!(chunked_matches(&ac, text.as_bytes(), 1), expected);
+        assert_eq!(chunked_matches(&ac, text.as_bytes(), 3), expected);
+    }
+
+    #[test]
+    fn test_streaming_invalid_utf8_resets_state() {
+        let ac = AhoCorasick::new(vec!["abc".to_string(), "bc".to_string()]).unwrap();
+        let mut stream = StreamingAcMatcher::new(&ac).unwrap();
+
+        // The invalid byte breaks "abc", but "bc" after it is found at its real offset
+        let matches = stream.push(b"ab\xffbc abc");
+        let spans: Vec<_> = matches
+            .iter()
+            .map(|m| (m.pattern_index, m.start, m.end))
+            .collect();
+        assert_eq!(spans, vec![(1, 3, 5), (0, 6, 9), (1, 7, 9)]);
+    }
+
+    #[test]
+    fn test_streaming_reset() {
+        let ac = AhoCorasick::new(vec!["abc".to_string()]).unwrap();
+        let mut stream = StreamingAcMatcher::new(&ac).unwrap();
+        assert!(stream.push(b"xab").is_empty());
+
+        stream.reset();
+        assert_eq!(stream.offset(), 0);
+        assert!(stream.push(b"c").is_empty());
+        assert_eq!(stream.push(b"abc")[0].start, 1);
+    }
+
+    #[test]
+    fn test_streaming_rejects_lookahead_config() {
+        let config = MatchConfig {
+            longest_match_only: true,
+            ..Default::default()
+        };
+        let ac = AhoCorasick::with_config(vec!["a".to_string()], config).unwrap();
+        assert!(matches!(
+            StreamingAcMatcher::new(&ac),
+            Err(Error::Unsupported(_))
+        ));
+    }
 }
@@ -0,0 +1,228 @@
+use crate::cs::error::{Error, Result};
+
+/// Maximum pattern length supported by the bit-parallel state (one bit per pattern byte).
+pub const MAX_PATTERN_LEN: usize = u64::BITS as usize;
+
+/// Finds all positions where the pattern matches the text with at most `max_errors`
+/// substituted bytes, using the Shift-Or (Bitap) algorithm.
+///
+/// The state for each error count `d` is a 64-bit word whose bit `i` is clear when
+/// `pattern[..=i]` matches the text ending at the current byte with at most `d`
+/// substitutions. All states advance with a shift and an OR per byte, so the search
+/// examines each text byte once.
+///
+/// # Arguments
+/// * `text` - The text to search in
+/// * `pattern` - The pattern to search for, at most 64 bytes long
+/// * `max_errors` - The maximum number of substitutions allowed in a match
+///
+/// # Returns
+/// * `Result<Vec<usize>>` - The starting positions of all approximate matches, in order
+///
+/// # Errors
+/// * `Error::EmptyPattern` if the pattern is empty
+/// * `Error::InputTooLarge` if the pattern is longer than 64 bytes
+/// * `Error::PatternTooLong` if pattern length exceeds text length
+///
+/// # Example
+/// ```
+/// use blocks::cs::string::bitap;
+///
+/// let text = "the quick brown fox";
+/// assert_eq!(bitap::find_all(text, "quick", 0).unwrap(), vec![4]);
+/// assert_eq!(bitap::find_all(text, "brawn", 1).unwrap(), vec![10]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n × (k + 1)) where n is the text length and k is `max_errors`
+/// * Space: O(k + σ) where σ = 256 is the alphabet size
+pub fn find_all(
+    text: impl AsRef<[u8]>,
+    pattern: impl AsRef<[u8]>,
+    max_errors: usize,
+) -> Result<Vec<usize>> {
+    search(text.as_ref(), pattern.as_ref(), max_errors, false)
+}
+
+/// Finds the first position where the pattern matches the text with at most
+/// `max_errors` substituted bytes, using the Shift-Or (Bitap) algorithm.
+///
+/// # Arguments
+/// * `text` - The text to search in
+/// * `pattern` - The pattern to search for, at most 64 bytes long
+/// * `max_errors` - The maximum number of substitutions allowed in a match
+///
+/// # Returns
+/// * `Result<Option<usize>>` - The starting position of the first approximate match if found
+///
+/// # Errors
+/// * `Error::EmptyPattern` if the pattern is empty
+/// * `Error::InputTooLarge` if the pattern is longer than 64 bytes
+/// * `Error::PatternTooLong` if pattern length exceeds text length
+///
+/// # Example
+/// ```
+/// use blocks::cs::string::bitap;
+///
+/// assert_eq!(bitap::find_first("hello world", "wxrld", 1).unwrap(), Some(6));
+/// assert_eq!(bitap::find_first("hello world", "wxrlx", 1).unwrap(), None);
+/// ```
+pub fn find_first(
+    text: impl AsRef<[u8]>,
+    pattern: impl AsRef<[u8]>,
+    max_errors: usize,
+) -> Result<Option<usize>> {
+    Ok(search(text.as_ref(), pattern.as_ref(), max_errors, true)?
+        .first()
+        .copied())
+}
+
+fn search(text: &[u8], pattern: &[u8], max_errors: usize, first_only: bool) -> Result<Vec<usize>> {
+    // Validate inputs
+    if pattern.is_empty() {
+        return Err(Error::empty_pattern());
+    }
+    if pattern.len() > MAX_PATTERN_LEN {
+        return Err(Error::input_too_large(pattern.len(), MAX_PATTERN_LEN));
+    }
+    if pattern.len() > text.len() {
+        return Err(Error::pattern_too_long(pattern.len(), text.len()));
+    }
+
+    let m = pattern.len();
+    let masks = build_masks(pattern);
+    let accept = 1u64 << (m - 1);
+
+    // More errors than pattern bytes behave the same as exactly m errors
+    let k = max_errors.min(m);
+    // state[d] starts with every bit set: no prefix has matched yet
+    let mut state = vec![!0u64; k + 1];
+    let mut matches = Vec::new();
+
+    for (i, &byte) in text.iter().enumerate() {
+        let mask = masks[byte as usize];
+
+        // Exact matches extend only on equal bytes
+        let mut prev_old = state[0];
+        state[0] = (state[0] << 1) | mask;
+
+        for s in state.iter_mut().skip(1) {
+            let old = *s;
+            // Either extend a d-error match on an equal byte, or substitute this byte
+            // onto a (d-1)-error match
+            *s = ((old << 1) | mask) & (prev_old << 1);
+            prev_old = old;
+        }
+
+        if state[k] & accept == 0 {
+            matches.push(i + 1 - m);
+            if first_only {
+                break;
+            }
+        }
+    }
+
+    Ok(matches)
+}
+
+/// Builds the per-byte masks: bit `i` of `masks[c]` is clear when `pattern[i] == c`.
+fn build_masks(pattern: &[u8]) -> [u64; 256] {
+    let mut masks = [!0u64; 256];
+    for (i, &byte) in pattern.iter().enumerate() {
+        masks[byte as usize] &= !(1u64 << i);
+    }
+    masks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Brute-force reference counting substitutions in every window
+    fn naive(text: &[u8], pattern: &[u8], max_errors: usize) -> Vec<usize> {
+        text.windows(pattern.len())
+            .enumerate()
+            .filter(|(_, w)| w.iter().zip(pattern).filter(|(a, b)| a != b).count() <= max_errors)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_pattern() {
+        assert!(matches!(find_all("hello", "", 0), Err(Error::EmptyPattern)));
+    }
+
+    #[test]
+    fn test_pattern_too_long() {
+        assert!(matches!(
+            find_all("hi", "hello", 1),
+            Err(Error::PatternTooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn test_pattern_exceeds_word_size() {
+        let text = "a".repeat(100);
+        let pattern = "a".repeat(65);
+        assert!(matches!(
+            find_first(&text, &pattern, 0),
+            Err(Error::InputTooLarge {
+                length: 65,
+                max_length: 64
+            })
+        ));
+
+        // Exactly 64 bytes is supported
+        let pattern = "a".repeat(64);
+        assert_eq!(find_first(&text, &pattern, 0).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn test_exact_match() {
+        let text = "AABAACAADAABAAABAA";
+        assert_eq!(find_all(text, "AABA", 0).unwrap(), vec![0, 9, 13]);
+        assert_eq!(find_first(text, "AABA", 0).unwrap(), Some(0));
+        assert_eq!(find_first(text, "ABAB", 0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_single_substitution() {
+        let text = "approximate matching";
+        assert_eq!(find_first(text, "matchinx", 0).unwrap(), None);
+        assert_eq!(find_first(text, "matchinx", 1).unwrap(), Some(12));
+        assert_eq!(find_first(text, "apprax", 1).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn test_errors_exceed_budget() {
+        let text = "hello world";
+        // "wyrlx" needs two substitutions to become "world"
+        assert_eq!(find_first(text, "wyrlx", 1).unwrap(), None);
+        assert_eq!(find_first(text, "wyrlx", 2).unwrap(), Some(6));
+    }
+
+    #[test]
+    fn test_errors_cover_whole_pattern() {
+        // Every window matches once all bytes may be substituted
+        assert_eq!(find_all("abcd", "xy", 2).unwrap(), vec![0, 1, 2]);
+        assert_eq!(find_all("abcd", "xy", 10).unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_matches_naive_reference() {
+        let text: Vec<u8> = (0..500u32)
+            .map(|i| b"acgt"[(i * 7919 % 13 % 4) as usize])
+            .collect();
+        for pattern in [&b"acg"[..], b"gattaca", b"tttt", b"acgtacgtac"] {
+            for k in 0..=3 {
+                assert_eq!(
+                    find_all(&text, pattern, k).unwrap(),
+                    naive(&text, pattern, k),
+                    "mismatch for pattern {:?} with {} errors",
+                    pattern,
+                    k
+                );
+            }
+        }
+    }
+}
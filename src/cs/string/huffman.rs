@@ -0,0 +1,332 @@
+//! Huffman coding: optimal prefix codes built from symbol frequencies.
+//!
+//! A [`HuffmanTree`] is built by repeatedly merging the two least frequent subtrees
+//! from a min-heap. Each symbol's code is the path from the root to its leaf, with
+//! `false` for a left edge and `true` for a right edge.
+//!
+//! # Example
+//! ```
+//! use blocks::cs::string::huffman::{self, HuffmanTree};
+//! use std::collections::HashMap;
+//!
+//! let text = "abracadabra";
+//! let mut frequencies = HashMap::new();
+//! for ch in text.chars() {
+//!     *frequencies.entry(ch).or_insert(0) += 1;
+//! }
+//!
+//! let tree = HuffmanTree::new(&frequencies).unwrap();
+//! let bits = huffman::encode(text, &tree.codes()).unwrap();
+//! assert_eq!(bits.len(), 23);
+//! assert_eq!(huffman::decode(&bits, &tree).unwrap(), text);
+//! ```
+
+use crate::cs::error::{Error, Result};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// A node of the code tree, stored in an arena.
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(char),
+    Internal { left: usize, right: usize },
+}
+
+/// A Huffman code tree, used to derive codes and to decode bit sequences.
+#[derive(Debug, Clone)]
+pub struct HuffmanTree {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+impl HuffmanTree {
+    /// Builds the optimal prefix code tree for the given symbol frequencies.
+    ///
+    /// Ties between equal weights are broken by symbol order and then by creation
+    /// order, so the same frequencies always produce the same tree. A single symbol
+    /// becomes a root leaf with the one-bit code `[false]`.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if `frequencies` is empty
+    ///
+    /// # Performance
+    /// * Time: O(σ log σ) where σ is the number of symbols
+    /// * Space: O(σ)
+    pub fn new(frequencies: &HashMap<char, usize>) -> Result<Self> {
+        if frequencies.is_empty() {
+            return Err(Error::invalid_input(
+                "Huffman coding requires at least one symbol",
+            ));
+        }
+
+        let mut symbols: Vec<(char, usize)> = frequencies.iter().map(|(&c, &f)| (c, f)).collect();
+        symbols.sort_unstable();
+
+        let mut nodes = Vec::with_capacity(2 * symbols.len() - 1);
+        // Min-heap on (weight, node index); indices make ties deterministic
+        let mut heap = BinaryHeap::with_capacity(symbols.len());
+        for (ch, frequency) in symbols {
+            heap.push(Reverse((frequency, nodes.len())));
+            nodes.push(Node::Leaf(ch));
+        }
+
+        while heap.len() > 1 {
+            let (Some(Reverse((w1, left))), Some(Reverse((w2, right)))) = (heap.pop(), heap.pop())
+            else {
+                unreachable!("heap holds at least two subtrees");
+            };
+            heap.push(Reverse((w1.saturating_add(w2), nodes.len())));
+            nodes.push(Node::Internal { left, right });
+        }
+
+        let root = nodes.len() - 1;
+        Ok(Self { nodes, root })
+    }
+
+    /// Returns the code of every symbol in the tree.
+    pub fn codes(&self) -> HashMap<char, Vec<bool>> {
+        let mut codes = HashMap::new();
+        if let Node::Leaf(ch) = self.nodes[self.root] {
+            codes.insert(ch, vec![false]);
+            return codes;
+        }
+
+        let mut stack = vec![(self.root, Vec::new())];
+        while let Some((index, path)) = stack.pop() {
+            match self.nodes[index] {
+                Node::Leaf(ch) => {
+                    codes.insert(ch, path);
+                }
+                Node::Internal { left, right } => {
+                    let mut right_path = path.clone();
+                    right_path.push(true);
+                    let mut left_path = path;
+                    left_path.push(false);
+                    stack.push((right, right_path));
+                    stack.push((left, left_path));
+                }
+            }
+        }
+        codes
+    }
+}
+
+/// Builds the optimal prefix code for the given symbol frequencies.
+///
+/// # Arguments
+/// * `frequencies` - The number of occurrences of each symbol
+///
+/// # Returns
+/// * The code of every symbol, or an empty map if there are no symbols
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::huffman;
+/// # use std::collections::HashMap;
+/// #
+/// let frequencies = HashMap::from([('a', 5), ('b', 2), ('c', 1)]);
+/// let codes = huffman::build_codes(&frequencies);
+/// assert_eq!(codes[&'a'].len(), 1);
+/// assert_eq!(codes[&'b'].len(), 2);
+/// assert_eq!(codes[&'c'].len(), 2);
+/// ```
+pub fn build_codes(frequencies: &HashMap<char, usize>) -> HashMap<char, Vec<bool>> {
+    HuffmanTree::new(frequencies)
+        .map(|tree| tree.codes())
+        .unwrap_or_default()
+}
+
+/// Encodes text as the concatenation of its symbols' codes.
+///
+/// # Arguments
+/// * `text` - The text to encode
+/// * `codes` - The code of each symbol, e.g. from [`build_codes`]
+///
+/// # Returns
+/// * `Result<Vec<bool>>` - The encoded bits
+///
+/// # Errors
+/// * `Error::InvalidInput` if the text contains a symbol without a code
+pub fn encode(text: &str, codes: &HashMap<char, Vec<bool>>) -> Result<Vec<bool>> {
+    let mut bits = Vec::new();
+    for ch in text.chars() {
+        let code = codes
+            .get(&ch)
+            .ok_or_else(|| Error::invalid_input(format!("No Huffman code for {:?}", ch)))?;
+        bits.extend_from_slice(code);
+    }
+    Ok(bits)
+}
+
+/// Decodes bits produced by [`encode`] by walking the code tree.
+///
+/// # Arguments
+/// * `bits` - The encoded bits
+/// * `tree` - The tree the codes were derived from
+///
+/// # Returns
+/// * `Result<String>` - The decoded text
+///
+/// # Errors
+/// * `Error::InvalidInput` if the bits end in the middle of a code, or do not form
+///   a valid code for a single-symbol tree
+pub fn decode(bits: &[bool], tree: &HuffmanTree) -> Result<String> {
+    let mut text = String::new();
+
+    if let Node::Leaf(ch) = tree.nodes[tree.root] {
+        // The only code is a single `false` bit
+        if bits.iter().any(|&bit| bit) {
+            return Err(Error::invalid_input("Invalid code for single-symbol tree"));
+        }
+        text.extend(std::iter::repeat_n(ch, bits.len()));
+        return Ok(text);
+    }
+
+    let mut current = tree.root;
+    for &bit in bits {
+        if let Node::Internal { left, right } = tree.nodes[current] {
+            current = if bit { right } else { left };
+        }
+        if let Node::Leaf(ch) = tree.nodes[current] {
+            text.push(ch);
+            current = tree.root;
+        }
+    }
+
+    if current != tree.root {
+        return Err(Error::invalid_input("Bits end in the middle of a code"));
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frequencies(text: &str) -> HashMap<char, usize> {
+        let mut frequencies = HashMap::new();
+        for ch in text.chars() {
+            *frequencies.entry(ch).or_insert(0) += 1;
+        }
+        frequencies
+    }
+
+    fn round_trip(text: &str) -> Vec<bool> {
+        let tree = HuffmanTree::new(&frequencies(text)).unwrap();
+        let bits = encode(text, &tree.codes()).unwrap();
+        assert_eq!(decode(&bits, &tree).unwrap(), text);
+        bits
+    }
+
+    #[test]
+    fn test_empty_frequencies() {
+        assert!(matches!(
+            HuffmanTree::new(&HashMap::new()),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(build_codes(&HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_single_symbol() {
+        let codes = build_codes(&HashMap::from([('z', 4)]));
+        assert_eq!(codes[&'z'], vec![false]);
+
+        let bits = round_trip("zzzz");
+        assert_eq!(bits, vec![false; 4]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip("abracadabra");
+        round_trip("the quick brown fox jumps over the lazy dog");
+        round_trip("ab");
+        round_trip("héllo wörld 🦀🦀");
+    }
+
+    #[test]
+    fn test_prefix_free() {
+        let codes = build_codes(&frequencies("mississippi river banks"));
+        for (a, code_a) in &codes {
+            for (b, code_b) in &codes {
+                if a != b {
+                    assert!(!code_b.starts_with(code_a), "{:?} prefixes {:?}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_frequent_symbols_get_shorter_codes() {
+        let frequencies = frequencies("aaaaaaaaaaaaaaaabbbbbbbbccccddeeeefg");
+        let codes = build_codes(&frequencies);
+        for (a, freq_a) in &frequencies {
+            for (b, freq_b) in &frequencies {
+                if freq_a > freq_b {
+                    assert!(
+                        codes[a].len() <= codes[b].len(),
+                        "{:?} (freq {}) has a longer code than {:?} (freq {})",
+                        a,
+                        freq_a,
+                        b,
+                        freq_b
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_optimal_length() {
+        // Classic example: weights 45, 13, 12, 16, 9, 5 give 224 bits in total
+        let frequencies = HashMap::from([
+            ('a', 45),
+            ('b', 13),
+            ('c', 12),
+            ('d', 16),
+            ('e', 9),
+            ('f', 5),
+        ]);
+        let codes = build_codes(&frequencies);
+        let total: usize = frequencies.iter().map(|(ch, f)| f * codes[ch].len()).sum();
+        assert_eq!(total, 224);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        // Separately built maps iterate in different orders
+        let text = "aabbccddeeff";
+        assert_eq!(
+            build_codes(&frequencies(text)),
+            build_codes(&frequencies(text))
+        );
+    }
+
+    #[test]
+    fn test_encode_unknown_symbol() {
+        let codes = build_codes(&frequencies("abc"));
+        assert!(matches!(encode("abd", &codes), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_decode_truncated() {
+        let text = "abracadabra";
+        let tree = HuffmanTree::new(&frequencies(text)).unwrap();
+        let codes = tree.codes();
+        let bits = encode(text, &codes).unwrap();
+
+        // Drop the final 'a' and cut into the code of the 'r' before it
+        assert!(codes[&'r'].len() > 1);
+        let cut = bits.len() - codes[&'a'].len() - 1;
+        assert!(matches!(
+            decode(&bits[..cut], &tree),
+            Err(Error::InvalidInput(_))
+        ));
+
+        let single = HuffmanTree::new(&HashMap::from([('x', 1)])).unwrap();
+        assert!(matches!(
+            decode(&[false, true], &single),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+}
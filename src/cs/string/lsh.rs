@@ -0,0 +1,317 @@
+//! MinHash signatures and banded locality-sensitive hashing for approximate
+//! near-duplicate detection.
+//!
+//! A [`MinHash`] maps a set of token hashes to a fixed-length signature such that the
+//! fraction of agreeing positions between two signatures estimates the Jaccard
+//! similarity of the underlying sets. An [`LshIndex`] splits signatures into bands and
+//! buckets each band, so that a query only has to look at documents sharing at least
+//! one identical band.
+//!
+//! # Example
+//! ```
+//! use blocks::cs::string::lsh::{jaccard_estimate, LshIndex, MinHash};
+//!
+//! let minhash = MinHash::new(64, 42).unwrap();
+//! let a: Vec<u64> = (0..100).collect();
+//! let b: Vec<u64> = (10..110).collect();
+//! let sig_a = minhash.signature(&a);
+//! let sig_b = minhash.signature(&b);
+//!
+//! let mut index = LshIndex::new(16, 4).unwrap();
+//! index.insert(0, &sig_a).unwrap();
+//! assert_eq!(index.query(&sig_b).unwrap(), vec![0]);
+//! assert!(jaccard_estimate(&sig_a, &sig_b).unwrap() > 0.6);
+//! ```
+
+use crate::cs::error::{Error, Result};
+use crate::cs::util::rng::mix;
+use std::collections::HashMap;
+
+/// A family of seeded hash functions used to compute MinHash signatures.
+#[derive(Debug, Clone)]
+pub struct MinHash {
+    /// One seed per hash function
+    seeds: Vec<u64>,
+}
+
+/// A MinHash signature: the minimum hash value of a set under each hash function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinHashSignature {
+    values: Vec<u64>,
+}
+
+impl MinHashSignature {
+    /// Returns the minimum hash value for each hash function
+    pub fn values(&self) -> &[u64] {
+        &self.values
+    }
+
+    /// Returns the number of hash functions in the signature
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the signature has no hash functions
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl MinHash {
+    /// Creates a MinHash with `num_hashes` hash functions derived from `seed`.
+    ///
+    /// The same `num_hashes` and `seed` always produce the same hash functions, so
+    /// signatures computed by separate instances can be compared.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if `num_hashes` is zero
+    pub fn new(num_hashes: usize, seed: u64) -> Result<Self> {
+        if num_hashes == 0 {
+            return Err(Error::invalid_input("MinHash requires at least one hash"));
+        }
+
+        let seeds = (0..num_hashes as u64)
+            .map(|i| mix(seed.wrapping_add(i)))
+            .collect();
+        Ok(Self { seeds })
+    }
+
+    /// Returns the number of hash functions
+    pub fn num_hashes(&self) -> usize {
+        self.seeds.len()
+    }
+
+    /// Computes the signature of a set of token hashes.
+    ///
+    /// Duplicate token hashes do not affect the signature. The signature of an empty
+    /// set has every value equal to `u64::MAX`.
+    ///
+    /// # Performance
+    /// * Time: O(n × h) where n is the number of tokens and h the number of hashes
+    /// * Space: O(h)
+    pub fn signature(&self, token_hashes: &[u64]) -> MinHashSignature {
+        let values = self
+            .seeds
+            .iter()
+            .map(|&seed| {
+                token_hashes
+                    .iter()
+                    .map(|&token| mix(token ^ seed))
+                    .min()
+                    .unwrap_or(u64::MAX)
+            })
+            .collect();
+        MinHashSignature { values }
+    }
+}
+
+/// Estimates the Jaccard similarity of two sets from their MinHash signatures as the
+/// fraction of hash functions on which the signatures agree.
+///
+/// # Errors
+/// * `Error::InvalidInput` if the signatures have different lengths
+pub fn jaccard_estimate(a: &MinHashSignature, b: &MinHashSignature) -> Result<f64> {
+    if a.len() != b.len() {
+        return Err(Error::invalid_input(format!(
+            "Signature lengths differ: {} and {}",
+            a.len(),
+            b.len()
+        )));
+    }
+    if a.is_empty() {
+        return Ok(0.0);
+    }
+
+    let agree = a
+        .values
+        .iter()
+        .zip(&b.values)
+        .filter(|(x, y)| x == y)
+        .count();
+    Ok(agree as f64 / a.len() as f64)
+}
+
+/// A banded LSH index over MinHash signatures.
+///
+/// Signatures of length `bands × rows` are split into `bands` consecutive groups of
+/// `rows` values. Two signatures become candidates of each other when any band is
+/// identical, which happens with probability `1 - (1 - s^rows)^bands` for sets with
+/// Jaccard similarity `s`.
+#[derive(Debug, Clone)]
+pub struct LshIndex {
+    bands: usize,
+    rows: usize,
+    /// For each band, the ids of signatures keyed by the hash of that band
+    buckets: Vec<HashMap<u64, Vec<usize>>>,
+}
+
+impl LshIndex {
+    /// Creates an empty index for signatures of length `bands × rows`.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if `bands` or `rows` is zero
+    pub fn new(bands: usize, rows: usize) -> Result<Self> {
+        if bands == 0 || rows == 0 {
+            return Err(Error::invalid_input(
+                "LSH index requires at least one band and one row",
+            ));
+        }
+
+        Ok(Self {
+            bands,
+            rows,
+            buckets: vec![HashMap::new(); bands],
+        })
+    }
+
+    /// Returns the signature length expected by this index
+    pub fn signature_len(&self) -> usize {
+        self.bands * self.rows
+    }
+
+    /// Inserts a signature under the caller-chosen identifier `id`.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if the signature length is not `bands × rows`
+    pub fn insert(&mut self, id: usize, signature: &MinHashSignature) -> Result<()> {
+        self.validate(signature)?;
+        for (band, key) in band_keys(signature, self.rows).enumerate() {
+            self.buckets[band].entry(key).or_default().push(id);
+        }
+        Ok(())
+    }
+
+    /// Returns the ids of all inserted signatures that share at least one band with
+    /// `signature`, sorted and without duplicates.
+    ///
+    /// Candidates are approximate: they should be verified with
+    /// [`jaccard_estimate`] or an exact similarity if false positives matter.
+    ///
+    /// # Errors
+    /// * `Error::InvalidInput` if the signature length is not `bands × rows`
+    pub fn query(&self, signature: &MinHashSignature) -> Result<Vec<usize>> {
+        self.validate(signature)?;
+        let mut candidates: Vec<usize> = band_keys(signature, self.rows)
+            .enumerate()
+            .filter_map(|(band, key)| self.buckets[band].get(&key))
+            .flatten()
+            .copied()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        Ok(candidates)
+    }
+
+    fn validate(&self, signature: &MinHashSignature) -> Result<()> {
+        if signature.len() != self.signature_len() {
+            return Err(Error::invalid_input(format!(
+                "Signature length {} does not match index length {}",
+                signature.len(),
+                self.signature_len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Hashes each band of `rows` consecutive signature values to a bucket key
+fn band_keys(signature: &MinHashSignature, rows: usize) -> impl Iterator<Item = u64> + '_ {
+    signature
+        .values
+        .chunks(rows)
+        .map(|band| band.iter().fold(0, |acc, &v| mix(acc ^ v)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cs::string::similarity::jaccard;
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(MinHash::new(0, 1), Err(Error::InvalidInput(_))));
+        assert!(matches!(LshIndex::new(0, 4), Err(Error::InvalidInput(_))));
+        assert!(matches!(LshIndex::new(4, 0), Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_signature_is_deterministic() {
+        let tokens: Vec<u64> = (0..50).collect();
+        let a = MinHash::new(32, 7).unwrap().signature(&tokens);
+        let b = MinHash::new(32, 7).unwrap().signature(&tokens);
+        let c = MinHash::new(32, 8).unwrap().signature(&tokens);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_signature_ignores_order_and_duplicates() {
+        let minhash = MinHash::new(16, 3).unwrap();
+        let a = minhash.signature(&[1, 2, 3]);
+        let b = minhash.signature(&[3, 1, 2, 2]);
+        assert_eq!(a, b);
+        assert_eq!(jaccard_estimate(&a, &b).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_jaccard_estimate_close_to_true_value() {
+        let minhash = MinHash::new(256, 11).unwrap();
+        let a: Vec<u32> = (0..90).collect();
+        let b: Vec<u32> = (10..100).collect();
+        let exact = jaccard(&a, &b);
+        assert_eq!(exact, 0.8);
+
+        let to_hashes = |v: &[u32]| v.iter().map(|&x| x as u64).collect::<Vec<_>>();
+        let sig_a = minhash.signature(&to_hashes(&a));
+        let sig_b = minhash.signature(&to_hashes(&b));
+        let estimate = jaccard_estimate(&sig_a, &sig_b).unwrap();
+        assert!(
+            (estimate - exact).abs() < 0.1,
+            "estimate {} too far from {}",
+            estimate,
+            exact
+        );
+    }
+
+    #[test]
+    fn test_jaccard_estimate_length_mismatch() {
+        let a = MinHash::new(8, 0).unwrap().signature(&[1, 2]);
+        let b = MinHash::new(16, 0).unwrap().signature(&[1, 2]);
+        assert!(matches!(
+            jaccard_estimate(&a, &b),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_index_finds_near_duplicates() {
+        let minhash = MinHash::new(128, 99).unwrap();
+        let mut index = LshIndex::new(32, 4).unwrap();
+
+        // Documents 0 and 1 share 80% of their tokens; document 2 is disjoint
+        let docs: [Vec<u64>; 3] = [
+            (0..90).collect(),
+            (10..100).collect(),
+            (1000..1090).collect(),
+        ];
+        let signatures: Vec<_> = docs.iter().map(|d| minhash.signature(d)).collect();
+        for (id, sig) in signatures.iter().enumerate() {
+            index.insert(id, sig).unwrap();
+        }
+
+        let candidates = index.query(&signatures[0]).unwrap();
+        assert!(candidates.contains(&0));
+        assert!(candidates.contains(&1));
+        assert!(!candidates.contains(&2));
+
+        assert_eq!(index.query(&signatures[2]).unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn test_index_length_mismatch() {
+        let mut index = LshIndex::new(4, 4).unwrap();
+        let sig = MinHash::new(15, 0).unwrap().signature(&[1]);
+        assert!(matches!(index.insert(0, &sig), Err(Error::InvalidInput(_))));
+        assert!(matches!(index.query(&sig), Err(Error::InvalidInput(_))));
+    }
+}
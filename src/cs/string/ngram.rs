@@ -0,0 +1,192 @@
+/// Extracts the overlapping character n-grams of a string.
+///
+/// N-grams are taken over Unicode scalar values, so multi-byte characters are never
+/// split.
+///
+/// # Arguments
+/// * `text` - The text to split
+/// * `n` - The number of characters in each n-gram
+///
+/// # Returns
+/// * Every run of `n` consecutive characters, in order. An empty vector if `n` is
+///   zero or larger than the number of characters.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::ngram;
+/// #
+/// assert_eq!(ngram::char_ngrams("cat", 2), vec!["ca", "at"]);
+/// assert!(ngram::char_ngrams("cat", 4).is_empty());
+/// ```
+///
+/// # Performance
+/// * Time: O(m × n) where m is the number of characters
+/// * Space: O(m × n)
+pub fn char_ngrams(text: &str, n: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    windows(&chars, n)
+        .map(|window| window.iter().collect())
+        .collect()
+}
+
+/// Extracts character n-grams after padding the text with `n - 1` boundary markers
+/// on each side, so the first and last characters also start and end n-grams.
+///
+/// # Arguments
+/// * `text` - The text to split
+/// * `n` - The number of characters in each n-gram
+/// * `marker` - The character used to pad both ends
+///
+/// # Returns
+/// * The n-grams of the padded text. An empty vector if `n` is zero or `text` is empty.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::ngram;
+/// #
+/// assert_eq!(ngram::padded_char_ngrams("cat", 2, '#'), vec!["#c", "ca", "at", "t#"]);
+/// ```
+pub fn padded_char_ngrams(text: &str, n: usize, marker: char) -> Vec<String> {
+    if n == 0 || text.is_empty() {
+        return Vec::new();
+    }
+
+    let padding = std::iter::repeat_n(marker, n - 1);
+    let chars: Vec<char> = padding.clone().chain(text.chars()).chain(padding).collect();
+    windows(&chars, n)
+        .map(|window| window.iter().collect())
+        .collect()
+}
+
+/// Extracts the overlapping word n-grams of a token sequence, joining the tokens of
+/// each n-gram with a single space.
+///
+/// # Arguments
+/// * `tokens` - The tokens to combine
+/// * `n` - The number of tokens in each n-gram
+///
+/// # Returns
+/// * Every run of `n` consecutive tokens, in order. An empty vector if `n` is zero
+///   or larger than the number of tokens.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::ngram;
+/// #
+/// assert_eq!(ngram::word_ngrams(&["the", "cat", "sat"], 2), vec!["the cat", "cat sat"]);
+/// ```
+///
+/// # Performance
+/// * Time: O(m × n) where m is the number of tokens
+/// * Space: O(m × n)
+pub fn word_ngrams(tokens: &[&str], n: usize) -> Vec<String> {
+    windows(tokens, n).map(|window| window.join(" ")).collect()
+}
+
+/// Extracts word n-grams after padding the tokens with `n - 1` boundary markers on
+/// each side.
+///
+/// # Arguments
+/// * `tokens` - The tokens to combine
+/// * `n` - The number of tokens in each n-gram
+/// * `marker` - The token used to pad both ends, e.g. `"<s>"`
+///
+/// # Returns
+/// * The n-grams of the padded tokens. An empty vector if `n` is zero or `tokens`
+///   is empty.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::ngram;
+/// #
+/// assert_eq!(
+///     ngram::padded_word_ngrams(&["hi", "there"], 2, "<s>"),
+///     vec!["<s> hi", "hi there", "there <s>"]
+/// );
+/// ```
+pub fn padded_word_ngrams(tokens: &[&str], n: usize, marker: &str) -> Vec<String> {
+    if n == 0 || tokens.is_empty() {
+        return Vec::new();
+    }
+
+    let padding = std::iter::repeat_n(marker, n - 1);
+    let padded: Vec<&str> = padding
+        .clone()
+        .chain(tokens.iter().copied())
+        .chain(padding)
+        .collect();
+    word_ngrams(&padded, n)
+}
+
+/// Like `slice::windows`, but yields nothing for `n == 0` instead of panicking.
+fn windows<T>(items: &[T], n: usize) -> std::slice::Windows<'_, T> {
+    // A window longer than the slice yields no items
+    items.windows(if n == 0 { items.len() + 1 } else { n })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_ngrams() {
+        assert_eq!(char_ngrams("cat", 2), vec!["ca", "at"]);
+        assert_eq!(char_ngrams("cat", 1), vec!["c", "a", "t"]);
+        assert_eq!(char_ngrams("cat", 3), vec!["cat"]);
+    }
+
+    #[test]
+    fn test_char_ngrams_unicode() {
+        assert_eq!(char_ngrams("añ🦀", 2), vec!["añ", "ñ🦀"]);
+    }
+
+    #[test]
+    fn test_word_ngrams() {
+        let tokens = ["the", "cat", "sat"];
+        assert_eq!(word_ngrams(&tokens, 2), vec!["the cat", "cat sat"]);
+        assert_eq!(word_ngrams(&tokens, 3), vec!["the cat sat"]);
+    }
+
+    #[test]
+    fn test_n_larger_than_input() {
+        assert!(char_ngrams("cat", 4).is_empty());
+        assert!(word_ngrams(&["the", "cat"], 3).is_empty());
+        assert!(char_ngrams("", 1).is_empty());
+        assert!(word_ngrams(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn test_n_zero() {
+        assert!(char_ngrams("cat", 0).is_empty());
+        assert!(word_ngrams(&["cat"], 0).is_empty());
+        assert!(padded_char_ngrams("cat", 0, '#').is_empty());
+        assert!(padded_word_ngrams(&["cat"], 0, "<s>").is_empty());
+    }
+
+    #[test]
+    fn test_padded_char_ngrams() {
+        assert_eq!(
+            padded_char_ngrams("ab", 3, '_'),
+            vec!["__a", "_ab", "ab_", "b__"]
+        );
+        // Unigrams need no padding
+        assert_eq!(padded_char_ngrams("ab", 1, '_'), vec!["a", "b"]);
+        assert!(padded_char_ngrams("", 2, '_').is_empty());
+    }
+
+    #[test]
+    fn test_padded_word_ngrams() {
+        assert_eq!(
+            padded_word_ngrams(&["the", "cat", "sat"], 3, "<s>"),
+            vec![
+                "<s> <s> the",
+                "<s> the cat",
+                "the cat sat",
+                "cat sat <s>",
+                "sat <s> <s>"
+            ]
+        );
+        // Padding lets a short input still produce n-grams
+        assert_eq!(padded_word_ngrams(&["hi"], 2, "|"), vec!["| hi", "hi |"]);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::cs::error::{Error, Result};
+use std::collections::HashSet;
+
+/// Computes the cosine similarity of two sparse vectors.
+///
+/// Each vector is given as `(index, value)` pairs sorted by strictly increasing index;
+/// indices that are absent are treated as zero.
+///
+/// # Arguments
+/// * `a` - The first sparse vector
+/// * `b` - The second sparse vector
+///
+/// # Returns
+/// * `Ok(similarity)` - The cosine of the angle between `a` and `b`, or `0.0` if
+///   either vector has zero norm
+/// * `Err(Error)` - The indices of a vector are not strictly increasing
+///
+/// # Examples
+/// ```
+/// use blocks::cs::string::similarity;
+///
+/// let a = [(0, 1.0), (3, 2.0)];
+/// let b = [(1, 5.0), (2, 1.0)];
+/// assert_eq!(similarity::cosine_sparse(&a, &b).unwrap(), 0.0);
+/// assert!((similarity::cosine_sparse(&a, &a).unwrap() - 1.0).abs() < 1e-12);
+/// ```
+///
+/// # Performance
+/// * Time: O(n + m) where n and m are the number of stored entries
+/// * Space: O(1)
+pub fn cosine_sparse(a: &[(u32, f64)], b: &[(u32, f64)]) -> Result<f64> {
+    validate_sorted(a)?;
+    validate_sorted(b)?;
+
+    let mut dot = 0.0;
+    let mut i = 0;
+    let mut j = 0;
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                dot += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    let norm_a = a.iter().map(|&(_, v)| v * v).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|&(_, v)| v * v).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return Ok(0.0);
+    }
+
+    Ok(dot / (norm_a * norm_b))
+}
+
+/// Computes the Jaccard similarity `|A ∩ B| / |A ∪ B|` of two sets of indices.
+///
+/// The inputs may be in any order; duplicate indices are counted once.
+/// Two empty sets are considered identical and have similarity `1.0`.
+///
+/// # Examples
+/// ```
+/// use blocks::cs::string::similarity;
+///
+/// assert_eq!(similarity::jaccard(&[1, 2, 3], &[1, 2, 3]), 1.0);
+/// assert_eq!(similarity::jaccard(&[1, 2], &[3, 4]), 0.0);
+/// assert_eq!(similarity::jaccard(&[1, 2, 3], &[2, 3, 4]), 0.5);
+/// ```
+///
+/// # Performance
+/// * Time: O(n + m)
+/// * Space: O(n + m)
+pub fn jaccard(a: &[u32], b: &[u32]) -> f64 {
+    let a: HashSet<u32> = a.iter().copied().collect();
+    let b: HashSet<u32> = b.iter().copied().collect();
+
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+/// Computes the Hamming distance between two 64-bit words, i.e. the number of
+/// bit positions in which they differ.
+///
+/// # Examples
+/// ```
+/// use blocks::cs::string::similarity;
+///
+/// assert_eq!(similarity::hamming_bits(0b1011, 0b1001), 1);
+/// assert_eq!(similarity::hamming_bits(0, u64::MAX), 64);
+/// ```
+pub fn hamming_bits(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Checks that the indices of a sparse vector are strictly increasing
+fn validate_sorted(v: &[(u32, f64)]) -> Result<()> {
+    if v.windows(2).all(|w| w[0].0 < w[1].0) {
+        Ok(())
+    } else {
+        Err(Error::invalid_input(
+            "Sparse vector indices must be strictly increasing",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jaccard_identical() {
+        assert_eq!(jaccard(&[1, 5, 9], &[1, 5, 9]), 1.0);
+        // Order and duplicates don't matter
+        assert_eq!(jaccard(&[9, 1, 5, 5], &[1, 5, 9]), 1.0);
+    }
+
+    #[test]
+    fn test_jaccard_disjoint() {
+        assert_eq!(jaccard(&[1, 2, 3], &[4, 5, 6]), 0.0);
+        assert_eq!(jaccard(&[1, 2, 3], &[]), 0.0);
+    }
+
+    #[test]
+    fn test_jaccard_partial_overlap() {
+        // |{2, 3}| / |{1, 2, 3, 4, 5}|
+        assert_eq!(jaccard(&[1, 2, 3], &[2, 3, 4, 5]), 0.4);
+    }
+
+    #[test]
+    fn test_jaccard_empty() {
+        assert_eq!(jaccard(&[], &[]), 1.0);
+    }
+
+    #[test]
+    fn test_cosine_orthogonal() {
+        let a = [(0, 1.0), (2, 3.0)];
+        let b = [(1, 2.0), (3, 4.0)];
+        assert_eq!(cosine_sparse(&a, &b).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_cosine_parallel() {
+        let a = [(1, 1.0), (4, 2.0)];
+        let b = [(1, 3.0), (4, 6.0)];
+        assert!((cosine_sparse(&a, &b).unwrap() - 1.0).abs() < 1e-12);
+
+        let c = [(1, -1.0), (4, -2.0)];
+        assert!((cosine_sparse(&a, &c).unwrap() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cosine_partial_overlap() {
+        // Dense equivalents: a = [1, 1, 0], b = [0, 1, 1]
+        let a = [(0, 1.0), (1, 1.0)];
+        let b = [(1, 1.0), (2, 1.0)];
+        assert!((cosine_sparse(&a, &b).unwrap() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cosine_zero_vector() {
+        assert_eq!(cosine_sparse(&[], &[(0, 1.0)]).unwrap(), 0.0);
+        assert_eq!(cosine_sparse(&[(0, 0.0)], &[(0, 1.0)]).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_cosine_unsorted_indices() {
+        let a = [(3, 1.0), (1, 1.0)];
+        assert!(matches!(
+            cosine_sparse(&a, &[(1, 1.0)]),
+            Err(Error::InvalidInput(_))
+        ));
+
+        let dup = [(1, 1.0), (1, 2.0)];
+        assert!(matches!(
+            cosine_sparse(&[(1, 1.0)], &dup),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_hamming_bits() {
+        assert_eq!(hamming_bits(0, 0), 0);
+        assert_eq!(hamming_bits(0b1100, 0b1010), 2);
+        assert_eq!(hamming_bits(u64::MAX, 0), 64);
+    }
+}
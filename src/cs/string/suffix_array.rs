@@ -1,17 +1,17 @@
-/// Suffix Array implementation using the prefix doubling algorithm.
-///
-/// A suffix array is a sorted array of all suffixes of a string. It allows for
-/// efficient string operations like pattern matching and longest common substring.
-/// This implementation uses the prefix doubling technique which runs in O(n log n) time
-/// and O(n) space.
-///
-/// # Example
-/// ```
-/// use blocks::cs::string::suffix_array::SuffixArray;
-///
-/// let text = "banana";
-/// let sa = SuffixArray::new(text);
-/// ```
+//! Suffix Array implementation using the prefix doubling algorithm.
+//!
+//! A suffix array is a sorted array of all suffixes of a string. It allows for
+//! efficient string operations like pattern matching and longest common substring.
+//! This implementation uses the prefix doubling technique which runs in O(n log n) time
+//! and O(n) space.
+//!
+//! # Example
+//! ```
+//! use blocks::cs::string::suffix_array::SuffixArray;
+//!
+//! let text = "banana";
+//! let sa = SuffixArray::new(text);
+//! ```
 
 /// Result type for string search operations
 pub type SearchResult = Result<Vec<usize>, String>;
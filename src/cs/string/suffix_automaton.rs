@@ -11,6 +11,8 @@ struct State {
     next: HashMap<char, usize>,
     /// End positions for all substrings in this state
     end_pos: HashSet<usize>,
+    /// Number of end positions, i.e. occurrences of each substring in this state
+    endpos_count: usize,
     /// Marks if this is a terminal state
     is_terminal: bool,
 }
@@ -22,6 +24,7 @@ impl State {
             link: None,
             next: HashMap::new(),
             end_pos: HashSet::new(),
+            endpos_count: 0,
             is_terminal: false,
         }
     }
@@ -66,6 +69,7 @@ impl SuffixAutomaton {
         let cur = self.states.len();
         self.states.push(State::new(self.states[p].len + 1));
         self.states[cur].add_pos(pos);
+        self.states[cur].endpos_count = 1;
 
         // Add transitions back while no edge on `ch`
         while p != usize::MAX && !self.states[p].next.contains_key(&ch) {
@@ -89,6 +93,7 @@ impl SuffixAutomaton {
                 self.states[clone].next = self.states[q].next.clone();
                 self.states[clone].link = self.states[q].link;
                 // The clone initially has no end positions; they'll be set by propagate_positions()
+                // (cloned states never contribute an occurrence of their own)
 
                 // Redirect transitions that pointed to q
                 while p != usize::MAX && self.states[p].next.get(&ch) == Some(&q) {
@@ -114,7 +119,8 @@ impl SuffixAutomaton {
         self.states[0].is_terminal = true;
     }
 
-    /// Propagate end positions up the suffix‐link tree in topological order (by length).
+    /// Propagate end positions and their counts up the suffix‐link tree in topological
+    /// order (by length).
     fn propagate_positions(&mut self) {
         // Sort states by length ascending
         let mut order: Vec<usize> = (0..self.states.len()).collect();
@@ -128,20 +134,92 @@ impl SuffixAutomaton {
                 for p in positions {
                     self.states[link].add_pos(p);
                 }
+                // Every occurrence ending in i also ends in its suffix link
+                self.states[link].endpos_count += self.states[i].endpos_count;
             }
         }
     }
 
-    /// Checks if a pattern is a substring by simply walking transitions.
-    pub fn contains(&self, pattern: &str) -> bool {
+    /// Walks the transitions for `pattern`, returning the state reached if it is a substring.
+    fn walk(&self, pattern: &str) -> Option<usize> {
         let mut s = 0;
         for ch in pattern.chars() {
-            match self.states[s].next.get(&ch) {
-                Some(&nx) => s = nx,
-                None => return false,
+            s = *self.states[s].next.get(&ch)?;
+        }
+        Some(s)
+    }
+
+    /// Checks if a pattern is a substring by simply walking transitions.
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.walk(pattern).is_some()
+    }
+
+    /// Counts the (possibly overlapping) occurrences of `pattern` in the original text.
+    ///
+    /// Runs in O(m) for a pattern of m characters, since the count is precomputed per
+    /// state. An empty pattern has no occurrences, matching `find_all`.
+    ///
+    /// # Example
+    /// ```
+    /// use blocks::cs::string::SuffixAutomaton;
+    ///
+    /// let sa = SuffixAutomaton::new("abcbc");
+    /// assert_eq!(sa.occurrence_count("bc"), 2);
+    /// assert_eq!(sa.occurrence_count("cb"), 1);
+    /// assert_eq!(sa.occurrence_count("ca"), 0);
+    /// ```
+    pub fn occurrence_count(&self, pattern: &str) -> usize {
+        if pattern.is_empty() {
+            return 0;
+        }
+
+        self.walk(pattern)
+            .map_or(0, |s| self.states[s].endpos_count)
+    }
+
+    /// Finds the longest string that is a substring of both the automaton's text and
+    /// `other`.
+    ///
+    /// `other` is fed through the automaton while tracking the length of the longest
+    /// suffix of the prefix read so far that is a substring of the text. On a missing
+    /// transition, suffix links are followed until the character can be matched. If
+    /// several common substrings have the greatest length, the one that occurs first in
+    /// `other` is returned.
+    ///
+    /// Runs in O(m) amortized for `other` of m characters.
+    ///
+    /// # Example
+    /// ```
+    /// use blocks::cs::string::SuffixAutomaton;
+    ///
+    /// let sa = SuffixAutomaton::new("banana");
+    /// assert_eq!(sa.longest_common_substring("ananas"), "anana");
+    /// assert_eq!(sa.longest_common_substring("xyz"), "");
+    /// ```
+    pub fn longest_common_substring(&self, other: &str) -> String {
+        let chars: Vec<char> = other.chars().collect();
+        let mut state = 0;
+        let mut len = 0;
+        // (length, end index in `chars`) of the best match so far
+        let mut best = (0, 0);
+
+        for (i, &ch) in chars.iter().enumerate() {
+            // Shorten the match until it can be extended by `ch`
+            while state != 0 && !self.states[state].next.contains_key(&ch) {
+                state = self.states[state].link.unwrap_or(0);
+                len = self.states[state].len;
+            }
+            if let Some(&next) = self.states[state].next.get(&ch) {
+                state = next;
+                len += 1;
+            }
+            if len > best.0 {
+                best = (len, i + 1);
             }
         }
-        true
+
+        let (best_len, end) = best;
+        chars[end - best_len..end].iter().collect()
     }
 
     /// Find all start positions of `pattern` in the original text.
@@ -151,13 +229,9 @@ impl SuffixAutomaton {
         }
 
         // Walk the automaton
-        let mut s = 0;
-        for ch in pattern.chars() {
-            match self.states[s].next.get(&ch) {
-                Some(&nx) => s = nx,
-                None => return Vec::new(),
-            }
-        }
+        let Some(s) = self.walk(pattern) else {
+            return Vec::new();
+        };
 
         // Only collect positions from the final state we reached
         let mut result: Vec<_> = self.states[s]
@@ -206,6 +280,36 @@ mod tests {
         assert_eq!(sa.find_all("xyz"), vec![]);
     }
 
+    #[test]
+    fn test_occurrence_count() {
+        let sa = SuffixAutomaton::new("abcbc");
+        assert_eq!(sa.occurrence_count("bc"), 2);
+        assert_eq!(sa.occurrence_count("c"), 2);
+        assert_eq!(sa.occurrence_count("abcbc"), 1);
+        assert_eq!(sa.occurrence_count("bcb"), 1);
+        assert_eq!(sa.occurrence_count("cc"), 0);
+        assert_eq!(sa.occurrence_count(""), 0);
+
+        let sa = SuffixAutomaton::new("aaaaa");
+        assert_eq!(sa.occurrence_count("a"), 5);
+        assert_eq!(sa.occurrence_count("aa"), 4);
+        assert_eq!(sa.occurrence_count("aaaaaa"), 0);
+    }
+
+    #[test]
+    fn test_occurrence_count_matches_find_all() {
+        let text = "abracadabra cadabra";
+        let sa = SuffixAutomaton::new(text);
+        for pattern in ["a", "abra", "cad", "bra ", "ra", "z", "dabra"] {
+            assert_eq!(
+                sa.occurrence_count(pattern),
+                sa.find_all(pattern).len(),
+                "count mismatch for {:?}",
+                pattern
+            );
+        }
+    }
+
     #[test]
     fn test_empty_pattern() {
         let text = "banana";
@@ -261,4 +365,52 @@ mod tests {
         assert_eq!(sa.find_all("ana"), vec![]);
         assert_eq!(sa.find_all("AnA"), vec![1, 3]);
     }
+
+    #[test]
+    fn test_longest_common_substring() {
+        let sa = SuffixAutomaton::new("banana");
+        assert_eq!(sa.longest_common_substring("ananas"), "anana");
+        assert_eq!(sa.longest_common_substring("xyz"), "");
+        assert_eq!(sa.longest_common_substring(""), "");
+        assert_eq!(sa.longest_common_substring("banana"), "banana");
+        // Mismatches force the match back through suffix links
+        assert_eq!(sa.longest_common_substring("nabanxnanab"), "nana");
+
+        let empty = SuffixAutomaton::new("");
+        assert_eq!(empty.longest_common_substring("abc"), "");
+    }
+
+    #[test]
+    fn test_longest_common_substring_first_of_ties() {
+        let sa = SuffixAutomaton::new("abxcd");
+        // "ab" and "cd" are both common; the one earlier in `other` wins
+        assert_eq!(sa.longest_common_substring("cd-ab"), "cd");
+        assert_eq!(sa.longest_common_substring("ab-cd"), "ab");
+    }
+
+    #[test]
+    fn test_longest_common_substring_unicode() {
+        let sa = SuffixAutomaton::new("こんにちは世界");
+        assert_eq!(sa.longest_common_substring("さようなら世界!"), "世界");
+    }
+
+    #[test]
+    fn test_longest_common_substring_matches_brute_force() {
+        let text: String = (0..200u32)
+            .map(|i| ['a', 'b', 'c'][(i * 7919 % 11 % 3) as usize])
+            .collect();
+        let other: String = (0..150u32)
+            .map(|i| ['a', 'b', 'c'][(i * 104_729 % 13 % 3) as usize])
+            .collect();
+
+        let sa = SuffixAutomaton::new(&text);
+        let lcs = sa.longest_common_substring(&other);
+        assert!(text.contains(&lcs) && other.contains(&lcs));
+
+        // No longer common substring exists
+        let longer = lcs.len() + 1;
+        assert!(
+            (0..=other.len().saturating_sub(longer)).all(|i| !text.contains(&other[i..i + longer]))
+        );
+    }
 }
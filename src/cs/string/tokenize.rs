@@ -0,0 +1,290 @@
+use std::collections::HashSet;
+
+/// Abbreviations whose trailing period does not end a sentence, compared
+/// case-insensitively and without the final period.
+const ABBREVIATIONS: &[&str] = &[
+    "dr", "e.g", "fig", "i.e", "jr", "mr", "mrs", "ms", "prof", "sr", "st", "vs",
+];
+
+/// Splits text on runs of Unicode whitespace.
+///
+/// Punctuation stays attached to the surrounding characters.
+///
+/// # Arguments
+/// * `text` - The text to split
+///
+/// # Returns
+/// * The non-empty whitespace-separated tokens, in order
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::tokenize;
+/// #
+/// assert_eq!(tokenize::whitespace("Hi,  there!\n"), vec!["Hi,", "there!"]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n)
+/// * Space: O(n)
+pub fn whitespace(text: &str) -> Vec<String> {
+    text.split_whitespace().map(str::to_string).collect()
+}
+
+/// Extracts the maximal runs of alphanumeric characters.
+///
+/// Letters and digits from any script count, so accented and non-Latin words stay
+/// whole. Everything else, including apostrophes, separates tokens, so the
+/// contraction "it's" becomes "it" and "s".
+///
+/// # Arguments
+/// * `text` - The text to split
+///
+/// # Returns
+/// * The alphanumeric runs, in order
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::tokenize;
+/// #
+/// assert_eq!(tokenize::regex_word("Don't panic, café!"), vec!["Don", "t", "panic", "café"]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n)
+/// * Space: O(n)
+pub fn regex_word(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Removes stopwords from a token sequence.
+///
+/// Matching is exact, so tokens and stopwords should be normalized the same way
+/// beforehand, e.g. both lowercased.
+///
+/// # Arguments
+/// * `tokens` - The tokens to filter
+/// * `stopwords` - The tokens to drop
+///
+/// # Returns
+/// * The tokens not in `stopwords`, in their original order
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::tokenize;
+/// # use std::collections::HashSet;
+/// #
+/// let tokens = tokenize::whitespace("the cat sat on a mat");
+/// let stopwords: HashSet<String> = ["the", "a", "on"].map(String::from).into();
+/// assert_eq!(tokenize::remove_stopwords(&tokens, &stopwords), vec!["cat", "sat", "mat"]);
+/// ```
+///
+/// # Performance
+/// * Time: O(n) expected, hashing each token once
+/// * Space: O(n)
+pub fn remove_stopwords(tokens: &[String], stopwords: &HashSet<String>) -> Vec<String> {
+    tokens
+        .iter()
+        .filter(|token| !stopwords.contains(*token))
+        .cloned()
+        .collect()
+}
+
+/// Splits text into sentences ending in `.`, `!` or `?`.
+///
+/// A run of terminators such as `"?!"` or `"..."` ends a sentence together, along
+/// with any closing quotes or brackets after it, but only when followed by
+/// whitespace or the end of the text, so decimals like `3.14` are not split. A single
+/// period after a common abbreviation such as "Dr" or "e.g" does not end a sentence.
+/// Sentences are trimmed of surrounding whitespace, and trailing text without a
+/// terminator forms a final sentence.
+///
+/// # Arguments
+/// * `text` - The text to split
+///
+/// # Returns
+/// * The non-empty sentences, in order, including their terminators
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::string::tokenize;
+/// #
+/// assert_eq!(
+///     tokenize::sentences("Dr. Smith is in. Is he busy? Yes!"),
+///     vec!["Dr. Smith is in.", "Is he busy?", "Yes!"]
+/// );
+/// ```
+///
+/// # Performance
+/// * Time: O(n)
+/// * Space: O(n)
+pub fn sentences(text: &str) -> Vec<String> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (position, c) = chars[i];
+        if !is_terminator(c) {
+            i += 1;
+            continue;
+        }
+
+        let mut end = i + 1;
+        while end < chars.len() && is_terminator(chars[end].1) {
+            end += 1;
+        }
+        let single_period = c == '.' && end == i + 1;
+        while end < chars.len() && is_closing(chars[end].1) {
+            end += 1;
+        }
+
+        let at_boundary = end == chars.len() || chars[end].1.is_whitespace();
+        if at_boundary && !(single_period && ends_with_abbreviation(&text[start..position])) {
+            let byte_end = chars.get(end).map_or(text.len(), |&(p, _)| p);
+            push_trimmed(&mut sentences, &text[start..byte_end]);
+            start = byte_end;
+        }
+        i = end;
+    }
+
+    push_trimmed(&mut sentences, &text[start..]);
+    sentences
+}
+
+fn is_terminator(c: char) -> bool {
+    matches!(c, '.' | '!' | '?')
+}
+
+fn is_closing(c: char) -> bool {
+    matches!(c, '"' | '\'' | ')' | ']' | '\u{201D}' | '\u{2019}')
+}
+
+/// Returns true if the last word of `text` is a known abbreviation.
+fn ends_with_abbreviation(text: &str) -> bool {
+    let word = text
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches(|c: char| !c.is_alphanumeric());
+    ABBREVIATIONS
+        .iter()
+        .any(|abbreviation| abbreviation.eq_ignore_ascii_case(word))
+}
+
+fn push_trimmed(sentences: &mut Vec<String>, sentence: &str) {
+    let sentence = sentence.trim();
+    if !sentence.is_empty() {
+        sentences.push(sentence.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whitespace() {
+        assert_eq!(
+            whitespace("  hello\tworld\n foo "),
+            vec!["hello", "world", "foo"]
+        );
+        assert_eq!(whitespace("Hi, there!"), vec!["Hi,", "there!"]);
+        assert!(whitespace(" \t\n").is_empty());
+    }
+
+    #[test]
+    fn test_regex_word_punctuation() {
+        assert_eq!(
+            regex_word("Hello, world! (Again): 2024."),
+            vec!["Hello", "world", "Again", "2024"]
+        );
+        assert!(regex_word("?!... --").is_empty());
+    }
+
+    #[test]
+    fn test_regex_word_contractions() {
+        assert_eq!(
+            regex_word("It's fine, we'll see"),
+            vec!["It", "s", "fine", "we", "ll", "see"]
+        );
+        // Curly apostrophes split the same way
+        assert_eq!(regex_word("can\u{2019}t"), vec!["can", "t"]);
+    }
+
+    #[test]
+    fn test_regex_word_unicode() {
+        assert_eq!(
+            regex_word("Ça va, naïve café? 東京 Straße"),
+            vec!["Ça", "va", "naïve", "café", "東京", "Straße"]
+        );
+    }
+
+    #[test]
+    fn test_remove_stopwords() {
+        let stopwords: HashSet<String> = ["the", "a", "of"].map(String::from).into();
+        let tokens = whitespace("the quick fox ate a slice of the pie");
+        assert_eq!(
+            remove_stopwords(&tokens, &stopwords),
+            vec!["quick", "fox", "ate", "slice", "pie"]
+        );
+
+        // Matching is exact, so differently cased tokens are kept
+        let tokens = whitespace("The fox");
+        assert_eq!(remove_stopwords(&tokens, &stopwords), vec!["The", "fox"]);
+        assert_eq!(remove_stopwords(&tokens, &HashSet::new()), tokens);
+    }
+
+    #[test]
+    fn test_sentences_terminators() {
+        assert_eq!(
+            sentences("Hello world. How are you? I'm fine!"),
+            vec!["Hello world.", "How are you?", "I'm fine!"]
+        );
+        assert_eq!(
+            sentences("Wait... what?! No."),
+            vec!["Wait...", "what?!", "No."]
+        );
+    }
+
+    #[test]
+    fn test_sentences_abbreviations_and_decimals() {
+        assert_eq!(
+            sentences("Dr. Smith met Mrs. Jones. They talked."),
+            vec!["Dr. Smith met Mrs. Jones.", "They talked."]
+        );
+        assert_eq!(
+            sentences("Use a tool, e.g. a hammer. Pi is 3.14 roughly."),
+            vec!["Use a tool, e.g. a hammer.", "Pi is 3.14 roughly."]
+        );
+    }
+
+    #[test]
+    fn test_sentences_closing_quotes() {
+        assert_eq!(
+            sentences("He said \"stop.\" Then he left (quickly!) and ran."),
+            vec!["He said \"stop.\"", "Then he left (quickly!)", "and ran."]
+        );
+    }
+
+    #[test]
+    fn test_sentences_unicode_and_unterminated() {
+        assert_eq!(
+            sentences("Ça va? Très bien. Et toi"),
+            vec!["Ça va?", "Très bien.", "Et toi"]
+        );
+        assert!(sentences("   ").is_empty());
+    }
+
+    #[test]
+    fn test_sentence_boundaries_are_stable() {
+        let text = "One. Two? Three! Dr. Four is here... Five.";
+        let split = sentences(text);
+        assert_eq!(split.len(), 5);
+        // Rejoining with single spaces reproduces the input exactly
+        assert_eq!(split.join(" "), text);
+    }
+}
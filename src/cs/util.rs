@@ -0,0 +1,5 @@
+pub mod dp_table;
+pub(crate) mod rng;
+
+// Re-export shared helpers
+pub use dp_table::DpTable;
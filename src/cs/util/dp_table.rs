@@ -0,0 +1,276 @@
+use std::ops::{Index, IndexMut};
+
+/// A two-dimensional table for dynamic programming, stored row-major in a single
+/// contiguous `Vec` rather than as nested vectors.
+///
+/// Cells are addressed by `(row, col)`. Every access is bounds-checked against both
+/// dimensions, so an out-of-range column cannot silently read into the next row.
+///
+/// # Examples
+/// ```
+/// # use blocks::cs::util::DpTable;
+/// #
+/// // Number of monotone lattice paths to each cell of a 3x4 grid
+/// let mut paths = DpTable::new(3, 4, 1u64);
+/// for r in 1..paths.rows() {
+///     for c in 1..paths.cols() {
+///         paths.set(r, c, paths[(r - 1, c)] + paths[(r, c - 1)]);
+///     }
+/// }
+/// assert_eq!(*paths.get(2, 3), 10);
+/// assert_eq!(paths.row(1), &[1, 2, 3, 4]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DpTable<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> DpTable<T> {
+    /// Creates a `rows` x `cols` table with every cell set to `default`.
+    ///
+    /// # Panics
+    /// * If `rows * cols` overflows `usize`
+    pub fn new(rows: usize, cols: usize, default: T) -> Self {
+        let len = rows
+            .checked_mul(cols)
+            .unwrap_or_else(|| panic!("DpTable dimensions {}x{} overflow usize", rows, cols));
+        Self {
+            rows,
+            cols,
+            cells: vec![default; len],
+        }
+    }
+
+    /// Resets every cell to `value`, keeping the dimensions.
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+}
+
+impl<T> DpTable<T> {
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns a reference to the cell at `(row, col)`.
+    ///
+    /// # Panics
+    /// * If `row` or `col` is out of bounds
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.cells[self.offset(row, col)]
+    }
+
+    /// Returns a mutable reference to the cell at `(row, col)`.
+    ///
+    /// # Panics
+    /// * If `row` or `col` is out of bounds
+    pub fn get_mut(&mut self, row: usize, col: usize) -> &mut T {
+        let offset = self.offset(row, col);
+        &mut self.cells[offset]
+    }
+
+    /// Stores `value` in the cell at `(row, col)`.
+    ///
+    /// # Panics
+    /// * If `row` or `col` is out of bounds
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        *self.get_mut(row, col) = value;
+    }
+
+    /// Returns the cells of one row as a slice.
+    ///
+    /// # Panics
+    /// * If `row` is out of bounds
+    pub fn row(&self, row: usize) -> &[T] {
+        self.check_row(row);
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns the cells of one row as a mutable slice.
+    ///
+    /// # Panics
+    /// * If `row` is out of bounds
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        self.check_row(row);
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Iterates over the cells of one column, from the first row to the last.
+    ///
+    /// # Panics
+    /// * If `col` is out of bounds
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.cols,
+            "DpTable column {} out of bounds for {}x{} table",
+            col,
+            self.rows,
+            self.cols
+        );
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// Iterates over the rows of the table, each as a slice.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on a zero chunk size; a table without columns has empty rows
+        let cols = self.cols.max(1);
+        let empty_rows = if self.cols == 0 { self.rows } else { 0 };
+        self.cells
+            .chunks_exact(cols)
+            .chain(std::iter::repeat_n(&[][..], empty_rows))
+    }
+
+    fn check_row(&self, row: usize) {
+        assert!(
+            row < self.rows,
+            "DpTable row {} out of bounds for {}x{} table",
+            row,
+            self.rows,
+            self.cols
+        );
+    }
+
+    fn offset(&self, row: usize, col: usize) -> usize {
+        assert!(
+            row < self.rows && col < self.cols,
+            "DpTable index ({}, {}) out of bounds for {}x{} table",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        row * self.cols + col
+    }
+}
+
+impl<T> Index<(usize, usize)> for DpTable<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DpTable<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_fills_default() {
+        let table = DpTable::new(2, 3, 7);
+        assert_eq!(table.rows(), 2);
+        assert_eq!(table.cols(), 3);
+        assert!(table.iter_rows().all(|row| row == [7, 7, 7]));
+    }
+
+    #[test]
+    fn test_row_major_addressing() {
+        let mut table = DpTable::new(3, 4, 0);
+        for r in 0..3 {
+            for c in 0..4 {
+                table.set(r, c, r * 10 + c);
+            }
+        }
+        assert_eq!(
+            table.cells,
+            vec![0, 1, 2, 3, 10, 11, 12, 13, 20, 21, 22, 23]
+        );
+        assert_eq!(*table.get(2, 1), 21);
+        assert_eq!(table[(1, 3)], 13);
+        assert_eq!(table.row(1), &[10, 11, 12, 13]);
+        assert_eq!(
+            table.column(2).copied().collect::<Vec<_>>(),
+            vec![2, 12, 22]
+        );
+        assert_eq!(table.iter_rows().count(), 3);
+    }
+
+    #[test]
+    fn test_mutation_through_references() {
+        let mut table = DpTable::new(2, 2, 1);
+        *table.get_mut(0, 1) += 4;
+        table[(1, 0)] = 9;
+        table.row_mut(1)[1] = 3;
+        assert_eq!(
+            table.iter_rows().collect::<Vec<_>>(),
+            vec![&[1, 5][..], &[9, 3]]
+        );
+
+        table.fill(0);
+        assert!(table.iter_rows().flatten().all(|&v| v == 0));
+    }
+
+    #[test]
+    fn test_edit_distance_table() {
+        let (a, b) = (b"kitten", b"sitting");
+        let mut dist = DpTable::new(a.len() + 1, b.len() + 1, 0usize);
+        for i in 0..=a.len() {
+            dist[(i, 0)] = i;
+        }
+        for j in 0..=b.len() {
+            dist[(0, j)] = j;
+        }
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let substitution = dist[(i - 1, j - 1)] + usize::from(a[i - 1] != b[j - 1]);
+                dist[(i, j)] = substitution
+                    .min(dist[(i - 1, j)] + 1)
+                    .min(dist[(i, j - 1)] + 1);
+            }
+        }
+        assert_eq!(dist[(a.len(), b.len())], 3);
+    }
+
+    #[test]
+    fn test_empty_dimensions() {
+        let table = DpTable::new(3, 0, 'x');
+        assert_eq!(table.iter_rows().count(), 3);
+        assert!(table.row(2).is_empty());
+
+        let table = DpTable::new(0, 5, 'x');
+        assert_eq!(table.iter_rows().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "DpTable index (0, 4) out of bounds for 3x4 table")]
+    fn test_column_out_of_bounds_panics() {
+        // Offset 4 is inside the backing Vec, so this must not alias (1, 0)
+        let table = DpTable::new(3, 4, 0);
+        table.get(0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "DpTable index (3, 0) out of bounds for 3x4 table")]
+    fn test_row_out_of_bounds_panics() {
+        let mut table = DpTable::new(3, 4, 0);
+        table.set(3, 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "DpTable row 5 out of bounds for 3x4 table")]
+    fn test_row_slice_out_of_bounds_panics() {
+        let table = DpTable::new(3, 4, 0);
+        table.row(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "DpTable column 4 out of bounds for 3x4 table")]
+    fn test_column_iterator_out_of_bounds_panics() {
+        let table = DpTable::new(3, 4, 0);
+        let _ = table.column(4);
+    }
+}
@@ -0,0 +1,31 @@
+//! SplitMix64 mixing, shared by modules that need cheap, seedable pseudo-randomness
+//! without depending on an external RNG crate.
+
+/// Amount the SplitMix64 state advances between outputs
+pub(crate) const SPLITMIX_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// SplitMix64 finalizer, used as a fast, well-distributed 64-bit mixing function
+pub(crate) fn mix(mut z: u64) -> u64 {
+    z = z.wrapping_add(SPLITMIX_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns output `index` of the SplitMix64 generator whose initial state is `seed`
+pub(crate) fn splitmix64(seed: u64, index: u64) -> u64 {
+    mix(seed.wrapping_add(index.wrapping_mul(SPLITMIX_GAMMA)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_reference_splitmix64() {
+        // First outputs of the reference generator seeded with 0
+        assert_eq!(splitmix64(0, 0), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(splitmix64(0, 1), 0x6E78_9E6A_A1B9_65F4);
+        assert_eq!(splitmix64(0, 2), 0x06C4_5D18_8009_454F);
+    }
+}
@@ -1,5 +1,5 @@
 pub mod cs;
 pub mod error;
 
-pub use cs::{search, sort, string};
+pub use cs::{compression, numeric, range_query, search, signal, sort, stats, string, util};
 pub use error::{Error, Result};